@@ -25,8 +25,7 @@ pub use receipt::{
     AnyReceiptEnvelope, Eip658Value, Receipt, ReceiptEnvelope, ReceiptWithBloom, TxReceipt,
 };
 
-mod request;
-pub use request::Request;
+pub use alloy_eips::eip7685::Request;
 
 pub mod transaction;
 #[cfg(feature = "kzg")]
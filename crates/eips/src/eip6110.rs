@@ -11,6 +11,13 @@ use alloy_rlp::{RlpDecodable, RlpEncodable};
 pub const MAINNET_DEPOSIT_CONTRACT_ADDRESS: Address =
     address!("00000000219ab540356cbb839cbe05303d7705fa");
 
+/// The [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) request type for deposit requests.
+pub const DEPOSIT_REQUEST_TYPE: u8 = 0x00;
+
+/// The size in bytes of a packed deposit request: `pubkey (48) || withdrawal_credentials (32) ||
+/// amount (8) || signature (96) || index (8)`.
+pub const DEPOSIT_REQUEST_PACKED_SIZE: usize = 192;
+
 /// This structure maps onto the deposit object from [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -31,3 +38,70 @@ pub struct DepositRequest {
     #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
     pub index: u64,
 }
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for DepositRequest {
+    /// Zeroes all fields of the request, including its public key material.
+    ///
+    /// The request is `Copy`, so it is not zeroized on drop and this must be called explicitly.
+    fn zeroize(&mut self) {
+        self.pubkey.0.zeroize();
+        self.withdrawal_credentials.0.zeroize();
+        self.amount.zeroize();
+        self.signature.0.zeroize();
+        self.index.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, fixed_bytes, hex};
+    use alloy_rlp::{Decodable, Encodable};
+
+    /// A 32 ETH deposit with `0x01` withdrawal credentials.
+    fn deposit() -> DepositRequest {
+        DepositRequest {
+            pubkey: fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"),
+            withdrawal_credentials: b256!("010000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e"),
+            amount: 32_000_000_000,
+            signature: fixed_bytes!("a473ec266d4fb4adbf3d104aa714f9f11032fd8ab6d8829fc40b52c86f6485d7928cc2ebd4646f3fe3f374be11d905bf4be275fa86f3889d82a9f7dc5e41dd32a543997d84f12798350c09bdef2cdb171bf41ed3e4a5f808af2feb0c56263009"),
+            index: 1234,
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_deposit_request_roundtrip() {
+        let original_request = deposit();
+
+        let mut buf = Vec::new();
+        original_request.encode(&mut buf);
+        assert_eq!(buf.len(), original_request.length());
+        assert_eq!(buf, hex!("f8bdb08e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793ba0010000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e850773594000b860a473ec266d4fb4adbf3d104aa714f9f11032fd8ab6d8829fc40b52c86f6485d7928cc2ebd4646f3fe3f374be11d905bf4be275fa86f3889d82a9f7dc5e41dd32a543997d84f12798350c09bdef2cdb171bf41ed3e4a5f808af2feb0c562630098204d2"));
+
+        let decoded = DepositRequest::decode(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, original_request);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_deposit_request() {
+        let input = r#"{"pubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","withdrawalCredentials":"0x010000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e","amount":"0x773594000","signature":"0xa473ec266d4fb4adbf3d104aa714f9f11032fd8ab6d8829fc40b52c86f6485d7928cc2ebd4646f3fe3f374be11d905bf4be275fa86f3889d82a9f7dc5e41dd32a543997d84f12798350c09bdef2cdb171bf41ed3e4a5f808af2feb0c56263009","index":"0x4d2"}"#;
+
+        let request: DepositRequest = serde_json::from_str(input).unwrap();
+        assert_eq!(request, deposit());
+
+        let s = serde_json::to_string(&request).unwrap();
+        assert_eq!(s, input);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_zeroize_deposit_request() {
+        use zeroize::Zeroize;
+
+        let mut request = deposit();
+        request.zeroize();
+        assert_eq!(request, DepositRequest::default());
+    }
+}
@@ -0,0 +1,83 @@
+//! Borrowed view of a [`WithdrawalRequest`].
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::FixedBytes;
+use alloy_rlp::RlpEncodable;
+
+/// A borrowed view of a [`WithdrawalRequest`], created with [`WithdrawalRequest::as_ref`].
+///
+/// This avoids copying the validator public key when requests only need to be inspected or
+/// encoded. The RLP encoding is identical to that of the owned request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, RlpEncodable)]
+pub struct WithdrawalRequestRef<'a> {
+    /// Address of the source of the exit.
+    pub source_address: &'a SourceAddress,
+    /// Validator public key.
+    pub validator_pubkey: &'a FixedBytes<48>,
+    /// Amount of withdrawn ether in gwei.
+    pub amount: Gwei,
+}
+
+impl WithdrawalRequestRef<'_> {
+    /// Copies the referenced fields into an owned [`WithdrawalRequest`].
+    pub const fn to_owned(self) -> WithdrawalRequest {
+        WithdrawalRequest {
+            source_address: *self.source_address,
+            validator_pubkey: *self.validator_pubkey,
+            amount: self.amount,
+        }
+    }
+}
+
+impl<'a> From<&'a WithdrawalRequest> for WithdrawalRequestRef<'a> {
+    fn from(request: &'a WithdrawalRequest) -> Self {
+        request.as_ref()
+    }
+}
+
+impl From<WithdrawalRequestRef<'_>> for WithdrawalRequest {
+    fn from(request: WithdrawalRequestRef<'_>) -> Self {
+        request.to_owned()
+    }
+}
+
+impl WithdrawalRequest {
+    /// Returns a borrowed view of the request.
+    pub const fn as_ref(&self) -> WithdrawalRequestRef<'_> {
+        WithdrawalRequestRef {
+            source_address: &self.source_address,
+            validator_pubkey: &self.validator_pubkey,
+            amount: self.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{fixed_bytes, Address};
+    use alloy_rlp::Encodable;
+
+    #[test]
+    fn borrowed_encoding() {
+        let requests = [
+            WithdrawalRequest::default(),
+            WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(1)),
+                validator_pubkey: fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"),
+                amount: Gwei(354),
+            },
+        ];
+        for request in &requests {
+            let view = request.as_ref();
+            assert!(core::ptr::eq(view.validator_pubkey, &request.validator_pubkey));
+            assert_eq!(alloy_rlp::encode(view), alloy_rlp::encode(request));
+            assert_eq!(view.length(), request.length());
+            assert_eq!(view.to_owned(), *request);
+            assert_eq!(WithdrawalRequest::from(WithdrawalRequestRef::from(request)), *request);
+        }
+    }
+}
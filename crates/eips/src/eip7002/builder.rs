@@ -0,0 +1,77 @@
+//! Builder for [`WithdrawalRequest`]s.
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::FixedBytes;
+
+/// A builder for [`WithdrawalRequest`]s, created with [`WithdrawalRequest::builder`].
+///
+/// Fields that are not set are zeroed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WithdrawalRequestBuilder {
+    request: WithdrawalRequest,
+}
+
+impl WithdrawalRequestBuilder {
+    /// Sets the source address.
+    pub const fn source_address(mut self, source_address: SourceAddress) -> Self {
+        self.request.source_address = source_address;
+        self
+    }
+
+    /// Sets the validator public key.
+    pub const fn validator_pubkey(mut self, validator_pubkey: FixedBytes<48>) -> Self {
+        self.request.validator_pubkey = validator_pubkey;
+        self
+    }
+
+    /// Sets the amount in gwei.
+    pub const fn amount(mut self, amount: Gwei) -> Self {
+        self.request.amount = amount;
+        self
+    }
+
+    /// Builds the request.
+    pub const fn build(self) -> WithdrawalRequest {
+        self.request
+    }
+}
+
+impl WithdrawalRequest {
+    /// Returns a [`WithdrawalRequestBuilder`] for a zeroed request.
+    pub const fn builder() -> WithdrawalRequestBuilder {
+        WithdrawalRequestBuilder {
+            request: Self {
+                source_address: SourceAddress::ZERO,
+                validator_pubkey: FixedBytes::ZERO,
+                amount: Gwei::ZERO,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip7002::sample_request;
+    use alloy_primitives::{address, fixed_bytes};
+
+    #[test]
+    fn build_withdrawal_request() {
+        let source_address = SourceAddress(address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"));
+        let validator_pubkey = fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b");
+
+        let request = WithdrawalRequest::builder()
+            .source_address(source_address)
+            .validator_pubkey(validator_pubkey)
+            .amount(Gwei(354))
+            .build();
+        assert_eq!(request, sample_request());
+
+        let request = WithdrawalRequest::builder().amount(Gwei(354)).build();
+        assert_eq!(request, WithdrawalRequest { amount: Gwei(354), ..Default::default() });
+        assert_eq!(WithdrawalRequest::builder().build(), WithdrawalRequest::default());
+    }
+}
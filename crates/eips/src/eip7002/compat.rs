@@ -0,0 +1,79 @@
+//! Conversions between [`WithdrawalRequest`]s and the `H160` and `H256` types of
+//! [`primitive-types`](https://docs.rs/primitive-types), which are also re-exported by
+//! [`ethereum-types`](https://docs.rs/ethereum-types).
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::{Address, FixedBytes, B256};
+use primitive_types::{H160, H256};
+
+/// Converts an `H160` into an [`Address`].
+pub const fn h160_to_address(address: H160) -> Address {
+    Address::new(address.0)
+}
+
+/// Converts an [`Address`] into an `H160`.
+pub const fn address_to_h160(address: Address) -> H160 {
+    H160(address.0 .0)
+}
+
+/// Converts an `H256` into a [`B256`].
+pub const fn h256_to_b256(hash: H256) -> B256 {
+    B256::new(hash.0)
+}
+
+/// Converts a [`B256`] into an `H256`.
+pub const fn b256_to_h256(hash: B256) -> H256 {
+    H256(hash.0)
+}
+
+impl WithdrawalRequest {
+    /// Creates a new request from a source address given as an `H160`.
+    pub const fn from_h160(source_address: H160, validator_pubkey: [u8; 48], amount: Gwei) -> Self {
+        Self {
+            source_address: SourceAddress(h160_to_address(source_address)),
+            validator_pubkey: FixedBytes(validator_pubkey),
+            amount,
+        }
+    }
+
+    /// Returns the source address as an `H160`.
+    pub const fn source_address_h160(&self) -> H160 {
+        address_to_h160(self.source_address.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn h160_roundtrip() {
+        let address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let h160 = address_to_h160(address);
+        assert_eq!(h160.as_bytes(), address.as_slice());
+        assert_eq!(h160_to_address(h160), address);
+
+        let request = WithdrawalRequest::from_h160(h160, [0xab; 48], Gwei(354));
+        assert_eq!(
+            request,
+            WithdrawalRequest {
+                source_address: SourceAddress(address),
+                validator_pubkey: FixedBytes([0xab; 48]),
+                amount: Gwei(354),
+            }
+        );
+        assert_eq!(request.source_address_h160(), h160);
+    }
+
+    #[test]
+    fn h256_roundtrip() {
+        let hash = b256!("58f7ce834fbf740220f4f8c74dff5083d822e22cc84b6df4de96133214eab02a");
+        let h256 = b256_to_h256(hash);
+        assert_eq!(h256.as_bytes(), hash.as_slice());
+        assert_eq!(h256_to_b256(h256), hash);
+    }
+}
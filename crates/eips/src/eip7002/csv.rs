@@ -0,0 +1,131 @@
+//! Conversion of [`WithdrawalRequest`]s from and to CSV records.
+//!
+//! Records have the columns `source_address,validator_pubkey,amount`, where the address and public
+//! key are hex strings and the amount is a decimal number of gwei. Splitting lines into fields and
+//! skipping header rows is left to the caller's CSV reader.
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::string::{String, ToString};
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::hex;
+use core::fmt;
+
+/// The number of columns in a withdrawal request CSV record.
+pub const CSV_RECORD_COLUMNS: usize = 3;
+
+/// Parses a [`WithdrawalRequest`] from a CSV record with the columns
+/// `source_address,validator_pubkey,amount`.
+///
+/// Surrounding whitespace is ignored in every field.
+pub fn from_csv_record(record: &[&str]) -> Result<WithdrawalRequest, CsvRecordError> {
+    let &[source_address, validator_pubkey, amount] = record else {
+        return Err(CsvRecordError::ColumnCount(record.len()));
+    };
+    Ok(WithdrawalRequest {
+        source_address: SourceAddress(
+            source_address.trim().parse().map_err(|_| CsvRecordError::InvalidSourceAddress)?,
+        ),
+        validator_pubkey: validator_pubkey
+            .trim()
+            .parse()
+            .map_err(|_| CsvRecordError::InvalidValidatorPubkey)?,
+        amount: Gwei(amount.trim().parse().map_err(|_| CsvRecordError::InvalidAmount)?),
+    })
+}
+
+impl WithdrawalRequest {
+    /// Returns the request as a CSV record that can be parsed with [`from_csv_record`].
+    ///
+    /// The source address is checksummed and the amount is written in decimal gwei.
+    pub fn to_csv_record(&self) -> [String; CSV_RECORD_COLUMNS] {
+        [
+            self.source_address.to_string(),
+            hex::encode_prefixed(self.validator_pubkey),
+            self.amount.get().to_string(),
+        ]
+    }
+}
+
+/// Error returned by [`from_csv_record`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CsvRecordError {
+    /// The record does not have exactly [`CSV_RECORD_COLUMNS`] columns.
+    ColumnCount(usize),
+    /// The source address is not a valid hex address.
+    InvalidSourceAddress,
+    /// The validator public key is not 48 bytes of hex.
+    InvalidValidatorPubkey,
+    /// The amount is not a decimal `u64`.
+    InvalidAmount,
+}
+
+impl fmt::Display for CsvRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ColumnCount(count) => {
+                write!(f, "expected {CSV_RECORD_COLUMNS} columns, got {count}")
+            }
+            Self::InvalidSourceAddress => f.write_str("invalid source address"),
+            Self::InvalidValidatorPubkey => f.write_str("invalid validator public key"),
+            Self::InvalidAmount => f.write_str("invalid amount, expected decimal gwei"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CsvRecordError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip7002::sample_request;
+
+    #[test]
+    fn csv_record_roundtrip() {
+        let partial = sample_request();
+        let full_exit = WithdrawalRequest { amount: Gwei::ZERO, ..partial };
+
+        for request in [partial, full_exit] {
+            let record = request.to_csv_record();
+            let fields = record.each_ref().map(String::as_str);
+            assert_eq!(from_csv_record(&fields), Ok(request));
+        }
+
+        let record = full_exit.to_csv_record();
+        assert_eq!(record[0], "0xaE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        assert_eq!(record[2], "0");
+    }
+
+    #[test]
+    fn csv_record_errors() {
+        let [source_address, validator_pubkey, _] = WithdrawalRequest::default().to_csv_record();
+        let (source_address, validator_pubkey) =
+            (source_address.as_str(), validator_pubkey.as_str());
+
+        assert_eq!(
+            from_csv_record(&[source_address, validator_pubkey, " 354 "]).map(|r| r.amount),
+            Ok(Gwei(354))
+        );
+        assert_eq!(
+            from_csv_record(&[source_address, validator_pubkey]),
+            Err(CsvRecordError::ColumnCount(2))
+        );
+        assert_eq!(
+            from_csv_record(&["0x1234", validator_pubkey, "0"]),
+            Err(CsvRecordError::InvalidSourceAddress)
+        );
+        assert_eq!(
+            from_csv_record(&[source_address, &validator_pubkey[..96], "0"]),
+            Err(CsvRecordError::InvalidValidatorPubkey)
+        );
+        assert_eq!(
+            from_csv_record(&[source_address, validator_pubkey, "0x162"]),
+            Err(CsvRecordError::InvalidAmount)
+        );
+    }
+}
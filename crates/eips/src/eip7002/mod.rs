@@ -0,0 +1,2921 @@
+//! Contains the system contract and [WithdrawalRequest] types, first introduced in the [Prague hardfork](https://github.com/ethereum/execution-apis/blob/main/src/engine/prague.md).
+//!
+//! See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002): Execution layer triggerable withdrawals
+
+mod borrowed;
+pub use borrowed::WithdrawalRequestRef;
+
+mod builder;
+pub use builder::WithdrawalRequestBuilder;
+
+mod csv;
+pub use csv::{from_csv_record, CsvRecordError, CSV_RECORD_COLUMNS};
+
+mod verify;
+pub use verify::{verify_batch, NoopVerifier, RequestVerifier};
+
+#[cfg(all(feature = "std", feature = "serde"))]
+mod ndjson;
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use ndjson::{from_ndjson, to_ndjson};
+
+pub mod slots;
+
+#[cfg(feature = "ethereum-types-compat")]
+pub mod compat;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+#[cfg(feature = "serde")]
+pub mod rpc;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "serde")]
+pub use serde_helpers::StrictWithdrawalRequest;
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::{string::String, vec, vec::Vec};
+
+use crate::{eip4895::GWEI_TO_WEI, eip7685::PackedRequest, system_contract::SystemContract, Gwei};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloy_primitives::{address, b256, hex, Address, Bytes, FixedBytes, Log, LogData, B256, U256};
+use alloy_rlp::{
+    Buf, BufMut, BytesMut, Decodable, Encodable, Header, RlpDecodable, RlpDecodableWrapper,
+    RlpEncodable, RlpEncodableWrapper,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::FusedIterator,
+    mem,
+    ops::{Deref, RangeInclusive},
+};
+
+/// The ceiling below which a [`WithdrawalRequest::amount`] in gwei is considered plausible: about
+/// 1.15 billion ether.
+///
+/// This is not enforced by the protocol, and only used to catch amounts mistakenly stored in wei.
+pub const MAX_PLAUSIBLE_GWEI_AMOUNT: u64 = 1 << 60;
+
+/// The caller to be used when calling the EIP-7002 withdrawal requests contract at the end of the
+/// block.
+pub const SYSTEM_ADDRESS: Address = address!("fffffffffffffffffffffffffffffffffffffffe");
+
+/// The address for the EIP-7002 withdrawal requests contract.
+pub const WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS: Address =
+    address!("00A3ca265EBcb825B45F985A16CEFB49958cE017");
+
+/// The code for the EIP-7002 withdrawal requests contract.
+pub static WITHDRAWAL_REQUEST_PREDEPLOY_CODE: Bytes =
+    Bytes::from_static(WithdrawalRequestContract::CODE);
+
+/// The code hash of the EIP-7002 withdrawal requests contract:
+/// `keccak256(WITHDRAWAL_REQUEST_PREDEPLOY_CODE)`.
+pub const WITHDRAWAL_REQUEST_PREDEPLOY_CODE_HASH: B256 =
+    b256!("58f7ce834fbf740220f4f8c74dff5083d822e22cc84b6df4de96133214eab02a");
+
+/// The [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) request type for withdrawal requests.
+pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+
+/// The size in bytes of a single withdrawal request in the packed output of the EIP-7002
+/// withdrawal requests contract: `source_address (20) || validator_pubkey (48) || amount (8)`.
+pub const WITHDRAWAL_REQUEST_PACKED_SIZE: usize = 76;
+
+const _: () = assert!(
+    WITHDRAWAL_REQUEST_PACKED_SIZE
+        == mem::size_of::<Address>() + mem::size_of::<FixedBytes<48>>() + mem::size_of::<u64>()
+);
+
+/// The size in bytes of the calldata accepted by the EIP-7002 withdrawal requests contract to add
+/// a request: `validator_pubkey (48) || amount (8)`.
+pub const WITHDRAWAL_REQUEST_CALLDATA_SIZE: usize = 56;
+
+const _: () = assert!(
+    WITHDRAWAL_REQUEST_CALLDATA_SIZE == mem::size_of::<FixedBytes<48>>() + mem::size_of::<u64>()
+);
+
+/// The minimum fee in wei for a withdrawal request.
+///
+/// See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002#configuration).
+pub const MIN_WITHDRAWAL_REQUEST_FEE: u128 = 1;
+
+/// Controls the maximum rate of change of the withdrawal request fee.
+///
+/// See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002#configuration).
+pub const WITHDRAWAL_REQUEST_FEE_UPDATE_FRACTION: u128 = 17;
+
+/// The maximum number of withdrawal requests that can be dequeued in a single block.
+///
+/// See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002#configuration).
+pub const MAX_WITHDRAWAL_REQUESTS_PER_BLOCK: u64 = 16;
+
+/// The target number of withdrawal requests added per block.
+///
+/// See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002#configuration).
+pub const TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK: u64 = 2;
+
+/// The storage slot of the withdrawal requests contract holding the current excess of withdrawal
+/// requests.
+pub const EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT: U256 = U256::ZERO;
+
+/// The value of [`EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT`] before the first system call to the
+/// withdrawal requests contract, which makes the contract reject new requests.
+pub const EXCESS_INHIBITOR: U256 = U256::MAX;
+
+/// The signature of the synthetic log produced by [`WithdrawalRequest::to_log`].
+pub const WITHDRAWAL_REQUEST_LOG_SIGNATURE: &str = "WithdrawalRequest(address,bytes,uint64)";
+
+/// The topic of the synthetic log produced by [`WithdrawalRequest::to_log`]:
+/// `keccak256("WithdrawalRequest(address,bytes,uint64)")`.
+pub const WITHDRAWAL_REQUEST_LOG_TOPIC: B256 =
+    b256!("cf3c1b7dc7a60cb47a599d47684b7852514eb0361f8e976024ad08785ef25f8b");
+
+/// The EIP-7002 withdrawal requests contract.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WithdrawalRequestContract;
+
+impl SystemContract for WithdrawalRequestContract {
+    const ADDRESS: Address = WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS;
+    const CODE: &'static [u8] = &hex!("3373fffffffffffffffffffffffffffffffffffffffe146090573615156028575f545f5260205ff35b366038141561012e5760115f54600182026001905f5b5f82111560595781019083028483029004916001019190603e565b90939004341061012e57600154600101600155600354806003026004013381556001015f3581556001016020359055600101600355005b6003546002548082038060101160a4575060105b5f5b81811460dd5780604c02838201600302600401805490600101805490600101549160601b83528260140152906034015260010160a6565b910180921460ed579060025560f8565b90505f6002555f6003555b5f548061049d141561010757505f5b60015460028282011161011c5750505f610122565b01600290035b5f555f600155604c025ff35b5f5ffd");
+    const CODE_HASH: B256 = WITHDRAWAL_REQUEST_PREDEPLOY_CODE_HASH;
+}
+
+/// A BLS12-381 validator public key, in its 48-byte compressed form.
+///
+/// This is a typed wrapper over the raw bytes, which prevents mixing up validator public keys
+/// with other 48-byte values. Its RLP and serde encodings are identical to those of the raw bytes.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    RlpEncodableWrapper,
+    RlpDecodableWrapper,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct ValidatorPubkey(pub FixedBytes<48>);
+
+impl From<FixedBytes<48>> for ValidatorPubkey {
+    fn from(pubkey: FixedBytes<48>) -> Self {
+        Self(pubkey)
+    }
+}
+
+impl From<ValidatorPubkey> for FixedBytes<48> {
+    fn from(pubkey: ValidatorPubkey) -> Self {
+        pubkey.0
+    }
+}
+
+/// The address of the source of a [`WithdrawalRequest`], i.e. the withdrawal address of the
+/// validator.
+///
+/// This is a typed wrapper over the raw address, which prevents mixing up withdrawal addresses
+/// with other accounts, such as a fee recipient. Its RLP, SSZ and serde encodings are identical to
+/// those of the raw address.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    RlpEncodableWrapper,
+    RlpDecodableWrapper,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+#[cfg_attr(feature = "ssz", ssz(struct_behaviour = "transparent"))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct SourceAddress(pub Address);
+
+impl SourceAddress {
+    /// The zero address.
+    pub const ZERO: Self = Self(Address::ZERO);
+}
+
+impl From<Address> for SourceAddress {
+    fn from(address: Address) -> Self {
+        Self(address)
+    }
+}
+
+impl From<SourceAddress> for Address {
+    fn from(address: SourceAddress) -> Self {
+        address.0
+    }
+}
+
+impl fmt::Display for SourceAddress {
+    /// Formats the address with its EIP-55 checksum, or abbreviated with the alternate flag.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl tree_hash::TreeHash for SourceAddress {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        Address::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.0.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        Address::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> tree_hash::Hash256 {
+        self.0.tree_hash_root()
+    }
+}
+
+/// Represents an execution layer triggerable withdrawal request.
+///
+/// See [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002).
+///
+/// Requests are ordered by source address, then validator public key, then amount. This ordering
+/// is a convenience for tests and snapshots only: the consensus ordering of requests in a block is
+/// the order in which the withdrawal requests contract returns them.
+///
+/// The field order is part of the stable serde representation, and must not change since binary
+/// formats such as bincode depend on it.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, RlpEncodable, RlpDecodable, Default,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct WithdrawalRequest {
+    /// Address of the source of the exit.
+    ///
+    /// The snake_case `source_address` is also accepted when deserializing.
+    #[cfg_attr(feature = "serde", serde(alias = "source_address"))]
+    pub source_address: SourceAddress,
+    /// Validator public key.
+    ///
+    /// The snake_case `validator_pubkey` is also accepted when deserializing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(alias = "validator_pubkey", deserialize_with = "serde_helpers::validator_pubkey")
+    )]
+    pub validator_pubkey: FixedBytes<48>,
+    /// Amount of withdrawn ether in gwei.
+    ///
+    /// Serialized as a `0x`-prefixed hex quantity. Decimal strings and JSON numbers are also
+    /// accepted when deserializing.
+    pub amount: Gwei,
+}
+
+impl WithdrawalRequest {
+    /// The maximum length of the RLP encoding of a request, reached for amounts of at least
+    /// `2^56`.
+    pub const MAX_ENCODED_LEN: usize = {
+        // source address, validator public key and amount, each with a one byte header
+        let payload_length = (1 + 20) + (1 + 48) + (1 + 8);
+        alloy_rlp::length_of_length(payload_length) + payload_length
+    };
+
+    /// Creates a new [`WithdrawalRequest`], validating its fields.
+    ///
+    /// Returns an error if the validator public key is all zeroes, or if the amount is
+    /// [`u64::MAX`], which some clients use as a sentinel value.
+    pub fn try_new(
+        source_address: SourceAddress,
+        validator_pubkey: FixedBytes<48>,
+        amount: Gwei,
+    ) -> Result<Self, WithdrawalRequestError> {
+        if validator_pubkey.is_zero() {
+            return Err(WithdrawalRequestError::ZeroValidatorPubkey);
+        }
+        if amount == Gwei::MAX {
+            return Err(WithdrawalRequestError::SentinelAmount);
+        }
+        Ok(Self { source_address, validator_pubkey, amount })
+    }
+
+    /// Checks the structural invariants of the request, e.g. before accepting untrusted data.
+    ///
+    /// These are the checks of [`Self::try_new`]: the validator public key must not be all zeroes,
+    /// and the amount must not be the [`u64::MAX`] sentinel. The length of the public key is
+    /// guaranteed by its type. See [`Self::check_plausible_amount`] for an additional, opt-in
+    /// check of the amount.
+    pub fn validate(&self) -> Result<(), WithdrawalRequestError> {
+        Self::try_new(self.source_address, self.validator_pubkey, self.amount).map(drop)
+    }
+
+    /// Checks that the amount is below [`MAX_PLAUSIBLE_GWEI_AMOUNT`], to catch amounts that were
+    /// mistakenly stored in wei.
+    ///
+    /// This is a lint rather than a protocol rule: the withdrawal requests contract accepts any
+    /// amount, so a request failing this check can still be included in a block. Only use it
+    /// where requests are constructed, not when validating requests of a block.
+    pub const fn check_plausible_amount(&self) -> Result<(), WithdrawalRequestError> {
+        if self.amount.0 >= MAX_PLAUSIBLE_GWEI_AMOUNT {
+            return Err(WithdrawalRequestError::ImplausibleAmount);
+        }
+        Ok(())
+    }
+
+    /// Returns whether this is a full exit request, i.e. whether the amount is zero.
+    pub const fn is_full_exit(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    /// Returns the kind of withdrawal requested.
+    pub const fn kind(&self) -> WithdrawalKind {
+        if self.amount.is_zero() {
+            WithdrawalKind::FullExit
+        } else {
+            WithdrawalKind::Partial(self.amount)
+        }
+    }
+
+    /// Returns the validator public key as a [`ValidatorPubkey`].
+    pub const fn typed_validator_pubkey(&self) -> ValidatorPubkey {
+        ValidatorPubkey(self.validator_pubkey)
+    }
+
+    /// Returns the `(source_address, validator_pubkey)` pair identifying the validator targeted by
+    /// this request, regardless of the amount.
+    ///
+    /// This can be used as a key to deduplicate requests per validator.
+    pub const fn validator_key(&self) -> (Address, FixedBytes<48>) {
+        (self.source_address.0, self.validator_pubkey)
+    }
+
+    /// Returns the amount as the 8 big-endian bytes it occupies in the packed layout.
+    pub const fn amount_be_bytes(&self) -> [u8; 8] {
+        self.amount.0.to_be_bytes()
+    }
+
+    /// Sets the amount from its 8 big-endian bytes in the packed layout.
+    pub const fn with_amount_be_bytes(mut self, amount: [u8; 8]) -> Self {
+        self.amount = Gwei(u64::from_be_bytes(amount));
+        self
+    }
+
+    /// Returns the amount of withdrawn ether in wei.
+    ///
+    /// Every [`u64`] amount converts without overflow, since the withdrawal requests contract
+    /// accepts any amount. Use [`Self::check_plausible_amount`] to catch amounts that were
+    /// mistakenly stored in wei.
+    pub fn amount_wei(&self) -> U256 {
+        self.amount.to_wei()
+    }
+
+    /// Returns the amount of withdrawn ether in ether, for display only.
+    ///
+    /// The conversion is lossy: amounts above `2^53` gwei (about 9 million ether) are not exactly
+    /// representable, and the division by `1e9` rounds. Use [`Self::amount`] or
+    /// [`Self::amount_wei`] for any computation.
+    pub fn amount_ether_f64(&self) -> f64 {
+        self.amount.0 as f64 / GWEI_TO_WEI as f64
+    }
+
+    /// Sets the amount of withdrawn ether from a value in wei.
+    ///
+    /// Returns an error, leaving the amount unchanged, if the value is not a whole number of gwei
+    /// or does not fit in a [`u64`] once converted to gwei.
+    pub fn set_amount_wei(&mut self, amount_wei: U256) -> Result<(), WithdrawalRequestError> {
+        let (amount, remainder) = amount_wei.div_rem(U256::from(GWEI_TO_WEI));
+        if !remainder.is_zero() {
+            return Err(WithdrawalRequestError::FractionalGweiAmount);
+        }
+        self.amount = Gwei(amount.try_into().map_err(|_| WithdrawalRequestError::AmountOverflow)?);
+        Ok(())
+    }
+
+    /// Compares two requests in constant time.
+    ///
+    /// Unlike the derived [`PartialEq`] implementation, this always compares all fields, so that
+    /// the time taken does not depend on where the requests differ.
+    #[cfg(feature = "subtle")]
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        use subtle::ConstantTimeEq;
+
+        self.source_address.0.as_slice().ct_eq(other.source_address.0.as_slice())
+            & self.validator_pubkey.as_slice().ct_eq(other.validator_pubkey.as_slice())
+            & self.amount.0.ct_eq(&other.amount.0)
+    }
+
+    /// Calculates the SSZ hash tree root of the request, as used by the consensus layer.
+    #[cfg(feature = "ssz")]
+    pub fn tree_hash_root(&self) -> B256 {
+        tree_hash::TreeHash::tree_hash_root(self)
+    }
+
+    /// Returns the JSON Schema of the serde representation of a request, serialized as a string.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema_string() -> String {
+        schemars::schema_for!(Self).as_value().to_string()
+    }
+
+    /// Parses the validator public key as a compressed BLS12-381 G1 point.
+    ///
+    /// Returns an error if the encoding is invalid, if the point is not on the curve or not in the
+    /// G1 subgroup, or if it is the point at infinity.
+    #[cfg(feature = "bls")]
+    pub fn validator_pubkey_g1(&self) -> Result<blst::min_pk::PublicKey, blst::BLST_ERROR> {
+        blst::min_pk::PublicKey::key_validate(self.validator_pubkey.as_slice())
+    }
+
+    /// Generates an arbitrary [`WithdrawalRequest`] whose validator public key is a valid
+    /// compressed BLS12-381 G1 point.
+    ///
+    /// The public key is derived from a secret key generated from arbitrary seed material. Unlike
+    /// the derived [`Arbitrary`](arbitrary::Arbitrary) implementation, this is suitable for
+    /// fuzzing code that validates the public key.
+    #[cfg(all(any(test, feature = "arbitrary"), feature = "bls"))]
+    pub fn arbitrary_valid(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let ikm: [u8; 32] = u.arbitrary()?;
+        let secret_key = blst::min_pk::SecretKey::key_gen(&ikm, &[])
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(Self {
+            source_address: u.arbitrary()?,
+            validator_pubkey: secret_key.sk_to_pk().compress().into(),
+            amount: u.arbitrary()?,
+        })
+    }
+
+    /// Decodes the packed output of the EIP-7002 withdrawal requests contract.
+    ///
+    /// The output is a concatenation of requests, each laid out as
+    /// `source_address (20) || validator_pubkey (48) || amount (8)`, with the amount encoded as a
+    /// big-endian integer.
+    ///
+    /// Returns an error if the length of the output is not a multiple of
+    /// [`WITHDRAWAL_REQUEST_PACKED_SIZE`].
+    pub fn decode_system_contract_output(output: &[u8]) -> Result<Vec<Self>, Eip7002DecodeError> {
+        if output.len() % WITHDRAWAL_REQUEST_PACKED_SIZE != 0 {
+            return Err(Eip7002DecodeError::InvalidLength(output.len()));
+        }
+        Ok(output
+            .chunks_exact(WITHDRAWAL_REQUEST_PACKED_SIZE)
+            .map(Self::from_packed_unchecked)
+            .collect())
+    }
+
+    /// Decodes the packed output of the EIP-7002 withdrawal requests contract from an untrusted
+    /// source, like [`Self::decode_system_contract_output`].
+    ///
+    /// The contract never returns more than [`MAX_WITHDRAWAL_REQUESTS_PER_BLOCK`] requests, so a
+    /// longer output is rejected with [`Eip7002DecodeError::TooManyRequests`] before anything is
+    /// allocated. This never panics, whatever the input.
+    pub fn decode_untrusted_system_output(output: &[u8]) -> Result<Vec<Self>, Eip7002DecodeError> {
+        let count = Self::count_in_system_output(output)?;
+        if count as u64 > MAX_WITHDRAWAL_REQUESTS_PER_BLOCK {
+            return Err(Eip7002DecodeError::TooManyRequests(count));
+        }
+        Self::decode_system_contract_output(output)
+    }
+
+    /// Returns the number of requests in the packed output of the EIP-7002 withdrawal requests
+    /// contract, without decoding them.
+    ///
+    /// Returns an error if the length of the output is not a multiple of
+    /// [`WITHDRAWAL_REQUEST_PACKED_SIZE`], like [`Self::decode_system_contract_output`].
+    pub const fn count_in_system_output(output: &[u8]) -> Result<usize, Eip7002DecodeError> {
+        if output.len() % WITHDRAWAL_REQUEST_PACKED_SIZE != 0 {
+            return Err(Eip7002DecodeError::InvalidLength(output.len()));
+        }
+        Ok(output.len() / WITHDRAWAL_REQUEST_PACKED_SIZE)
+    }
+
+    /// Lazily decodes the packed output of the EIP-7002 withdrawal requests contract.
+    ///
+    /// This is the streaming counterpart of [`Self::decode_system_contract_output`], and does not
+    /// allocate. Like it, an output whose length is not a multiple of
+    /// [`WITHDRAWAL_REQUEST_PACKED_SIZE`] is rejected up front: the iterator then yields a single
+    /// [`Eip7002DecodeError::InvalidLength`] error with the length of the whole output, and no
+    /// requests.
+    pub const fn iter_system_contract_output(output: &[u8]) -> WithdrawalRequestIter<'_> {
+        WithdrawalRequestIter { output }
+    }
+
+    /// Encodes the given requests into the packed layout emitted by the EIP-7002 withdrawal
+    /// requests contract.
+    ///
+    /// This is the inverse of [`Self::decode_system_contract_output`].
+    pub fn encode_system_contract_output(requests: &[Self]) -> Bytes {
+        let mut out = Vec::with_capacity(requests.len() * WITHDRAWAL_REQUEST_PACKED_SIZE);
+        for request in requests {
+            request.encode_packed(&mut out);
+        }
+        out.into()
+    }
+
+    /// Encodes the request into its fixed-width packed layout, for compact storage.
+    ///
+    /// This is the same layout as a single request in the output of the EIP-7002 withdrawal
+    /// requests contract.
+    pub fn to_compact(&self) -> [u8; WITHDRAWAL_REQUEST_PACKED_SIZE] {
+        let mut out = [0; WITHDRAWAL_REQUEST_PACKED_SIZE];
+        self.encode_packed(&mut out.as_mut_slice());
+        out
+    }
+
+    /// Decodes a request from its fixed-width packed layout.
+    ///
+    /// This is the inverse of [`Self::to_compact`].
+    pub fn from_compact(buf: &[u8; WITHDRAWAL_REQUEST_PACKED_SIZE]) -> Self {
+        Self::from_packed_unchecked(buf)
+    }
+
+    /// RLP-encodes the request into the given buffer, reserving [`Encodable::length`] bytes first.
+    ///
+    /// Buffers preallocated with the total length of the requests to encode are never reallocated.
+    pub fn encode_into(&self, out: &mut BytesMut) {
+        out.reserve(self.length());
+        self.encode(out);
+    }
+
+    /// RLP-encodes the request into a stack-allocated array.
+    ///
+    /// Returns the array and the length of the encoding, which is at most
+    /// [`Self::MAX_ENCODED_LEN`].
+    pub fn encode_to_array(&self) -> ([u8; Self::MAX_ENCODED_LEN], usize) {
+        let mut buf = [0; Self::MAX_ENCODED_LEN];
+        let mut out = &mut buf[..];
+        self.encode(&mut out);
+        let len = Self::MAX_ENCODED_LEN - out.len();
+        (buf, len)
+    }
+
+    /// Reads and decodes a single RLP-encoded request from the given reader.
+    ///
+    /// Only the bytes of the request are read, so the reader is left positioned directly after it.
+    /// Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors.
+    #[cfg(feature = "std")]
+    pub fn decode_from_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let invalid_data = |err: alloy_rlp::Error| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string())
+        };
+
+        let mut buf = [0; Self::MAX_ENCODED_LEN];
+        reader.read_exact(&mut buf[..1])?;
+        let (header_len, payload_length) = match buf[0] {
+            0xc0..=0xf7 => (1, (buf[0] - 0xc0) as usize),
+            // a valid request is at most `MAX_ENCODED_LEN` bytes, so the length fits in one byte
+            0xf8 => {
+                reader.read_exact(&mut buf[1..2])?;
+                (2, buf[1] as usize)
+            }
+            0xf9..=0xff => return Err(invalid_data(alloy_rlp::Error::Overflow)),
+            _ => return Err(invalid_data(alloy_rlp::Error::UnexpectedString)),
+        };
+
+        let len = header_len + payload_length;
+        if len > Self::MAX_ENCODED_LEN {
+            return Err(invalid_data(alloy_rlp::Error::Overflow));
+        }
+        reader.read_exact(&mut buf[header_len..len])?;
+        alloy_rlp::decode_exact(&buf[..len]).map_err(invalid_data)
+    }
+
+    /// Decodes an RLP-encoded request, accepting leading zero bytes in the amount.
+    ///
+    /// Some non-canonical encoders pad the amount with leading zeros, or encode amounts below
+    /// `0x80` as a one-byte string such as `0x8105` instead of the byte itself, which the
+    /// spec-compliant [`Decodable`] implementation rejects. Apart from that, the structure of the
+    /// request is validated as strictly as by [`Decodable`].
+    pub fn decode_lenient(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        let (mut payload, rest) = buf.split_at(header.payload_length);
+
+        let source_address = Decodable::decode(&mut payload)?;
+        let validator_pubkey = Decodable::decode(&mut payload)?;
+        // the string header is parsed by hand, since `Header` rejects single bytes below `0x80`
+        // that are encoded as a string
+        let amount = match *payload.first().ok_or(alloy_rlp::Error::InputTooShort)? {
+            ..=0x7f => {
+                let (amount, rest) = payload.split_at(1);
+                payload = rest;
+                amount
+            }
+            byte @ 0x80..=0xb7 => {
+                let len = (byte - 0x80) as usize;
+                if len > mem::size_of::<u64>() {
+                    return Err(alloy_rlp::Error::Overflow);
+                }
+                if payload.len() < 1 + len {
+                    return Err(alloy_rlp::Error::InputTooShort);
+                }
+                let (amount, rest) = payload[1..].split_at(len);
+                payload = rest;
+                amount
+            }
+            0xb8..=0xbf => return Err(alloy_rlp::Error::Overflow),
+            0xc0.. => return Err(alloy_rlp::Error::UnexpectedList),
+        };
+        let mut be = [0; mem::size_of::<u64>()];
+        be[mem::size_of::<u64>() - amount.len()..].copy_from_slice(amount);
+
+        if !payload.is_empty() {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: header.payload_length - payload.len(),
+            });
+        }
+        *buf = rest;
+        Ok(Self { source_address, validator_pubkey, amount: Gwei(u64::from_be_bytes(be)) })
+    }
+
+    /// Decodes an [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) encoded request, i.e. the
+    /// [`WITHDRAWAL_REQUEST_TYPE`] byte followed by the RLP-encoded request.
+    ///
+    /// On success, the buffer is advanced past the decoded request. Returns
+    /// [`Eip7002DecodeError::EmptyInput`] if the buffer is empty, and
+    /// [`Eip7002DecodeError::BadTypePrefix`] if it starts with a different request type.
+    pub fn decode_request(buf: &mut &[u8]) -> Result<Self, Eip7002DecodeError> {
+        match buf.first() {
+            None => Err(Eip7002DecodeError::EmptyInput),
+            Some(&WITHDRAWAL_REQUEST_TYPE) => {
+                buf.advance(1);
+                Ok(Self::decode(buf)?)
+            }
+            Some(&ty) => Err(Eip7002DecodeError::BadTypePrefix(ty)),
+        }
+    }
+
+    /// Decodes exactly one [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) encoded request,
+    /// like [`Self::decode_request`], rejecting any trailing bytes.
+    pub fn decode_request_exact(mut buf: &[u8]) -> Result<Self, Eip7002DecodeError> {
+        let request = Self::decode_request(&mut buf)?;
+        if !buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength.into());
+        }
+        Ok(request)
+    }
+
+    /// Decodes exactly one [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) encoded request,
+    /// tolerating a single trailing [`WITHDRAWAL_REQUEST_TYPE`] byte.
+    ///
+    /// Some tools append the type byte of the next request when concatenating encoded requests.
+    /// This ignores such an artifact, but still rejects any other trailing bytes, like
+    /// [`Self::decode_request_exact`].
+    pub fn decode_request_lenient(mut buf: &[u8]) -> Result<Self, Eip7002DecodeError> {
+        let request = Self::decode_request(&mut buf)?;
+        match buf {
+            [] | [WITHDRAWAL_REQUEST_TYPE] => Ok(request),
+            _ => Err(alloy_rlp::Error::UnexpectedLength.into()),
+        }
+    }
+
+    /// Encodes the request according to [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685), i.e.
+    /// the [`WITHDRAWAL_REQUEST_TYPE`] byte followed by the RLP-encoded request.
+    ///
+    /// This is the inverse of [`Self::decode_request`].
+    pub fn encode_request(&self, out: &mut dyn BufMut) {
+        out.put_u8(WITHDRAWAL_REQUEST_TYPE);
+        self.encode(out);
+    }
+
+    /// Converts the ABI-decoded `(address, bytes, uint64)` tuple of a withdrawal request, e.g. as
+    /// returned by an `eth_call`, into a request.
+    ///
+    /// Returns an error if the tokens do not have the expected types, or if the validator public
+    /// key is not 48 bytes long.
+    #[cfg(feature = "dyn-abi")]
+    pub fn from_abi_tokens(
+        tokens: &[alloy_dyn_abi::DynSolValue],
+    ) -> Result<Self, Eip7002DecodeError> {
+        use alloy_dyn_abi::DynSolValue;
+
+        let [DynSolValue::Address(source_address), DynSolValue::Bytes(validator_pubkey), DynSolValue::Uint(amount, 64)] =
+            tokens
+        else {
+            return Err(Eip7002DecodeError::InvalidAbiTokens);
+        };
+        let validator_pubkey = FixedBytes::try_from(validator_pubkey.as_slice()).map_err(|_| {
+            Eip7002DecodeError::UnexpectedLength { expected: 48, got: validator_pubkey.len() }
+        })?;
+        let amount = u64::try_from(*amount).map_err(|_| Eip7002DecodeError::InvalidAbiTokens)?;
+        Ok(Self {
+            source_address: SourceAddress(*source_address),
+            validator_pubkey,
+            amount: Gwei(amount),
+        })
+    }
+
+    /// Decodes an RLP-encoded request from a hex string, with or without a `0x` prefix.
+    ///
+    /// Returns an error if the string is empty or not valid hex, or if it is not exactly one
+    /// RLP-encoded request.
+    pub fn from_hex(s: &str) -> Result<Self, Eip7002DecodeError> {
+        let bytes = hex::decode(s)?;
+        if bytes.is_empty() {
+            return Err(Eip7002DecodeError::EmptyInput);
+        }
+        Ok(alloy_rlp::decode_exact(bytes)?)
+    }
+
+    /// Encodes the request as a `0x`-prefixed hex string of its RLP encoding.
+    ///
+    /// This is the inverse of [`Self::from_hex`].
+    pub fn to_hex(&self) -> String {
+        hex::encode_prefixed(alloy_rlp::encode(self))
+    }
+
+    /// Writes the request as a `0x`-prefixed hex string of its RLP encoding, without allocating.
+    ///
+    /// This writes the same string as [`Self::to_hex`], at most `2 + 2 * MAX_ENCODED_LEN` bytes.
+    pub fn write_hex<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let (buf, len) = self.encode_to_array();
+        w.write_str("0x")?;
+        buf[..len].iter().try_for_each(|byte| write!(w, "{byte:02x}"))
+    }
+
+    /// Returns the calldata that adds this request when sent to the withdrawal requests contract,
+    /// e.g. to simulate the request with an `eth_call`.
+    ///
+    /// The calldata is the [`WITHDRAWAL_REQUEST_CALLDATA_SIZE`] bytes `validator_pubkey ||
+    /// amount`, with the amount in big-endian gwei. The source address is not part of the calldata,
+    /// the contract uses the caller instead, so the call must be sent from
+    /// [`Self::source_address`] with at least the current [`fee`] as value.
+    pub fn to_predeploy_calldata(&self) -> Bytes {
+        let mut calldata = Vec::with_capacity(WITHDRAWAL_REQUEST_CALLDATA_SIZE);
+        calldata.extend_from_slice(self.validator_pubkey.as_slice());
+        calldata.extend_from_slice(&self.amount_be_bytes());
+        calldata.into()
+    }
+
+    /// Reconstructs a pending request from the three storage words of its entry in the queue of the
+    /// withdrawal requests contract, starting at slot
+    /// `QUEUE_STORAGE_OFFSET + index * QUEUE_ENTRY_SLOTS`, see [`slots`].
+    ///
+    /// When a request is added, the contract stores:
+    /// - `word0`: the caller, i.e. the source address, as a left-padded 32-byte word, so the
+    ///   address is in bytes `12..32`.
+    /// - `word1`: the first 32 bytes of the calldata, i.e. bytes `0..32` of the validator public
+    ///   key.
+    /// - `word2`: the next 32 bytes of the calldata, i.e. bytes `32..48` of the validator public
+    ///   key in bytes `0..16`, followed by the big-endian amount in bytes `16..24`. The remaining
+    ///   bytes are past the end of the calldata, and thus zero.
+    ///
+    /// The padding bytes are ignored, as the contract also ignores them when dequeuing requests.
+    pub fn from_queue_slots(word0: B256, word1: B256, word2: B256) -> Self {
+        let mut validator_pubkey = FixedBytes::<48>::ZERO;
+        validator_pubkey[..32].copy_from_slice(word1.as_slice());
+        validator_pubkey[32..].copy_from_slice(&word2[..16]);
+        let amount = u64::from_be_bytes(word2[16..24].try_into().expect("amount is 8 bytes"));
+        Self {
+            source_address: SourceAddress(Address::from_word(word0)),
+            validator_pubkey,
+            amount: Gwei(amount),
+        }
+    }
+
+    /// Converts the request into a synthetic log, for pipelines that ingest requests as logs.
+    ///
+    /// The log is emitted by [`WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS`], with
+    /// [`WITHDRAWAL_REQUEST_LOG_TOPIC`] as its only topic, and the packed request as its data.
+    pub fn to_log(&self) -> Log {
+        let mut data = Vec::with_capacity(WITHDRAWAL_REQUEST_PACKED_SIZE);
+        self.encode_packed(&mut data);
+        Log {
+            address: WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+            data: LogData::new_unchecked(vec![WITHDRAWAL_REQUEST_LOG_TOPIC], data.into()),
+        }
+    }
+
+    /// Converts a synthetic log produced by [`Self::to_log`] back into a request.
+    ///
+    /// Returns an error if the log topics are not exactly [`WITHDRAWAL_REQUEST_LOG_TOPIC`], or if
+    /// the log data is not [`WITHDRAWAL_REQUEST_PACKED_SIZE`] bytes long.
+    pub fn try_from_log(log: &Log) -> Result<Self, Eip7002DecodeError> {
+        if log.topics() != [WITHDRAWAL_REQUEST_LOG_TOPIC] {
+            return Err(Eip7002DecodeError::InvalidLogTopics);
+        }
+        if log.data.data.len() != WITHDRAWAL_REQUEST_PACKED_SIZE {
+            return Err(Eip7002DecodeError::UnexpectedLength {
+                expected: WITHDRAWAL_REQUEST_PACKED_SIZE,
+                got: log.data.data.len(),
+            });
+        }
+        Ok(Self::from_packed_unchecked(&log.data.data))
+    }
+
+    /// Decodes a single packed request. The buffer must be exactly
+    /// [`WITHDRAWAL_REQUEST_PACKED_SIZE`] bytes long.
+    pub(crate) fn from_packed_unchecked(buf: &[u8]) -> Self {
+        let (source_address, rest) = buf.split_at(20);
+        let (validator_pubkey, amount) = rest.split_at(48);
+        Self {
+            source_address: SourceAddress(Address::from_slice(source_address)),
+            validator_pubkey: FixedBytes::from_slice(validator_pubkey),
+            amount: Gwei(u64::from_be_bytes(amount.try_into().expect("packed amount is 8 bytes"))),
+        }
+    }
+}
+
+/// The kind of withdrawal requested by a [`WithdrawalRequest`], as returned by
+/// [`WithdrawalRequest::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WithdrawalKind {
+    /// A full exit of the validator, requested with an amount of zero.
+    FullExit,
+    /// A partial withdrawal of the given amount.
+    Partial(Gwei),
+}
+
+/// An iterator over the requests in the packed output of the EIP-7002 withdrawal requests
+/// contract.
+///
+/// Returned by [`WithdrawalRequest::iter_system_contract_output`]. The iterator is fused after the
+/// first error.
+#[derive(Clone, Debug)]
+pub struct WithdrawalRequestIter<'a> {
+    output: &'a [u8],
+}
+
+impl Iterator for WithdrawalRequestIter<'_> {
+    type Item = Result<WithdrawalRequest, Eip7002DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.output.is_empty() {
+            return None;
+        }
+        // only the full output can have an invalid length, since requests are consumed whole
+        if self.output.len() % WITHDRAWAL_REQUEST_PACKED_SIZE != 0 {
+            let len = self.output.len();
+            self.output = &[];
+            return Some(Err(Eip7002DecodeError::InvalidLength(len)));
+        }
+        let (packed, rest) = self.output.split_at(WITHDRAWAL_REQUEST_PACKED_SIZE);
+        self.output = rest;
+        Some(Ok(WithdrawalRequest::from_packed_unchecked(packed)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.output.len() {
+            len if len % WITHDRAWAL_REQUEST_PACKED_SIZE != 0 => 1,
+            len => len / WITHDRAWAL_REQUEST_PACKED_SIZE,
+        };
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for WithdrawalRequestIter<'_> {}
+
+impl FusedIterator for WithdrawalRequestIter<'_> {}
+
+impl From<(Address, FixedBytes<48>, u64)> for WithdrawalRequest {
+    fn from((source_address, validator_pubkey, amount): (Address, FixedBytes<48>, u64)) -> Self {
+        Self {
+            source_address: SourceAddress(source_address),
+            validator_pubkey,
+            amount: Gwei(amount),
+        }
+    }
+}
+
+impl From<WithdrawalRequest> for (Address, FixedBytes<48>, u64) {
+    fn from(request: WithdrawalRequest) -> Self {
+        (request.source_address.0, request.validator_pubkey, request.amount.0)
+    }
+}
+
+impl TryFrom<&[u8]> for WithdrawalRequest {
+    type Error = Eip7002DecodeError;
+
+    /// Decodes a single request from its packed layout. The slice must be exactly
+    /// [`WITHDRAWAL_REQUEST_PACKED_SIZE`] bytes long.
+    fn try_from(buf: &[u8]) -> Result<Self, Self::Error> {
+        let buf: &[u8; WITHDRAWAL_REQUEST_PACKED_SIZE] =
+            buf.try_into().map_err(|_| Eip7002DecodeError::UnexpectedLength {
+                expected: WITHDRAWAL_REQUEST_PACKED_SIZE,
+                got: buf.len(),
+            })?;
+        Ok(Self::from_compact(buf))
+    }
+}
+
+/// A [`WithdrawalRequest`] whose [`Arbitrary`](arbitrary::Arbitrary) implementation bounds the
+/// amount to a realistic validator balance, i.e. at most
+/// [`MAX_EFFECTIVE_BALANCE`](crate::eip7251::MAX_EFFECTIVE_BALANCE) gwei.
+///
+/// This avoids generating amounts such as [`u64::MAX`] that are treated specially by some
+/// processing code. The derived implementation on [`WithdrawalRequest`] is left unbounded.
+#[cfg(any(test, feature = "arbitrary"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArbitraryBoundedAmount(pub WithdrawalRequest);
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for ArbitraryBoundedAmount {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(WithdrawalRequest {
+            source_address: u.arbitrary()?,
+            validator_pubkey: u.arbitrary()?,
+            amount: Gwei(u.int_in_range(0..=crate::eip7251::MAX_EFFECTIVE_BALANCE)?),
+        }))
+    }
+}
+
+impl fmt::Display for WithdrawalRequest {
+    /// Formats the request for logging, abbreviating the source address and validator public key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "withdrawal(source={:#}, pubkey={:#}, amount={} gwei)",
+            self.source_address.0 .0, self.validator_pubkey, self.amount.0
+        )
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for WithdrawalRequest {
+    /// Zeroes all fields of the request, including its public key material.
+    ///
+    /// The request is `Copy`, so it is not zeroized on drop and this must be called explicitly.
+    fn zeroize(&mut self) {
+        self.source_address.0 .0.zeroize();
+        self.validator_pubkey.0.zeroize();
+        self.amount.0.zeroize();
+    }
+}
+
+/// Borsh serializes the fields as fixed-width values, for a total of
+/// [`WITHDRAWAL_REQUEST_PACKED_SIZE`] bytes. Unlike the packed layout, the amount is little-endian.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for WithdrawalRequest {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.source_address.0 .0.serialize(writer)?;
+        self.validator_pubkey.0.serialize(writer)?;
+        self.amount.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for WithdrawalRequest {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        Ok(Self {
+            source_address: SourceAddress(Address::new(<[u8; 20]>::deserialize_reader(reader)?)),
+            validator_pubkey: FixedBytes(<[u8; 48]>::deserialize_reader(reader)?),
+            amount: Gwei(u64::deserialize_reader(reader)?),
+        })
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl tree_hash::TreeHash for WithdrawalRequest {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Container
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> tree_hash::Hash256 {
+        let mut hasher = tree_hash::MerkleHasher::with_leaves(3);
+        hasher
+            .write(self.source_address.tree_hash_root().as_slice())
+            .expect("withdrawal request has three leaves");
+        hasher
+            .write(self.validator_pubkey.0.tree_hash_root().as_slice())
+            .expect("withdrawal request has three leaves");
+        hasher
+            .write(self.amount.tree_hash_root().as_slice())
+            .expect("withdrawal request has three leaves");
+        hasher.finish().expect("withdrawal request has three leaves")
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for WithdrawalRequest {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "WithdrawalRequest".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "alloy_eips::eip7002::WithdrawalRequest".into()
+    }
+
+    /// Describes the input accepted when deserializing: unknown fields are ignored, the fields
+    /// may also be spelled in snake case, hex strings may omit the `0x` prefix, and the amount may
+    /// also be a decimal string or a JSON number. Serialization always produces the camel case,
+    /// `0x`-prefixed form.
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let address = serde_json::json!({
+            "description": "Address of the source of the exit, as 20 hex encoded bytes.",
+            "type": "string",
+            "format": "address",
+            "pattern": "^(0[xX])?[0-9a-fA-F]{40}$"
+        });
+        let pubkey = serde_json::json!({
+            "description": "Validator public key, as 48 hex encoded bytes.",
+            "type": "string",
+            "format": "bytes48",
+            "pattern": "^(0[xX])?[0-9a-fA-F]{96}$"
+        });
+        schemars::json_schema!({
+            "description": "An EIP-7002 withdrawal request.",
+            "type": "object",
+            "properties": {
+                "sourceAddress": address,
+                "source_address": address,
+                "validatorPubkey": pubkey,
+                "validator_pubkey": pubkey,
+                "amount": {
+                    "description": "Amount of withdrawn ether in gwei, as a hex quantity, a decimal string or a number.",
+                    "format": "quantity",
+                    "anyOf": [
+                        { "type": "string", "pattern": "^0[xX][0-9a-fA-F]*$" },
+                        { "type": "string", "pattern": "^[0-9]+$" },
+                        { "type": "integer", "minimum": 0, "maximum": u64::MAX }
+                    ]
+                }
+            },
+            "required": ["amount"],
+            "allOf": [
+                { "oneOf": [{ "required": ["sourceAddress"] }, { "required": ["source_address"] }] },
+                {
+                    "oneOf": [
+                        { "required": ["validatorPubkey"] },
+                        { "required": ["validator_pubkey"] }
+                    ]
+                }
+            ]
+        })
+    }
+}
+
+/// Calculates the fee in wei for adding a withdrawal request, given the current excess of
+/// withdrawal requests.
+///
+/// This approximates `MIN_WITHDRAWAL_REQUEST_FEE * e ** (excess /
+/// WITHDRAWAL_REQUEST_FEE_UPDATE_FRACTION)` using the same Taylor expansion as the withdrawal
+/// requests contract. The result saturates at [`u128::MAX`] for excess values that the contract can
+/// not reach in practice.
+///
+/// See also [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002#fee-calculation).
+pub fn fee(excess: u64) -> u128 {
+    let numerator = U256::from(excess);
+    let denominator = U256::from(WITHDRAWAL_REQUEST_FEE_UPDATE_FRACTION);
+    let max_output = U256::from(u128::MAX) * denominator;
+
+    let mut i = U256::from(1);
+    let mut output = U256::ZERO;
+    let mut numerator_accum = U256::from(MIN_WITHDRAWAL_REQUEST_FEE) * denominator;
+    while !numerator_accum.is_zero() {
+        output += numerator_accum;
+        if output > max_output {
+            return u128::MAX;
+        }
+        numerator_accum = numerator_accum * numerator / (denominator * i);
+        i += U256::from(1);
+    }
+    (output / denominator).to()
+}
+
+/// Calculates the fee in wei for adding a withdrawal request, given the raw value of the
+/// [`EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT`] of the withdrawal requests contract.
+///
+/// The slot value can be read with `eth_getStorageAt` at [`WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS`].
+/// Returns `None` if the contract does not accept requests, i.e. the slot holds the
+/// [`EXCESS_INHIBITOR`], or any other excess that does not fit in a [`u64`].
+pub fn fee_from_excess_slot(value: U256) -> Option<u128> {
+    u64::try_from(value).ok().map(fee)
+}
+
+/// Returns the minimum value in wei that a caller must send with a withdrawal request, given the
+/// current excess of withdrawal requests.
+///
+/// This is [`fee`] as a [`U256`], for use as the `value` of a transaction. Unlike the request
+/// amount, which is in gwei, the fee is denominated in wei by the specification and is compared by
+/// the contract against the call value as is, so no scaling is applied.
+///
+/// Like [`fee`], the result saturates at [`u128::MAX`].
+pub fn required_fee_wei(excess: u64) -> U256 {
+    U256::from(fee(excess))
+}
+
+/// Calculates the excess of withdrawal requests for the next block, given the excess of the
+/// previous block and the number of requests added in the current block.
+///
+/// This is `max(previous_excess + count - TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK, 0)`, as updated by
+/// the withdrawal requests contract at the end of each block. See also
+/// [`TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK`].
+pub const fn compute_excess(previous_excess: u64, count: u64) -> u64 {
+    previous_excess.saturating_add(count).saturating_sub(TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK)
+}
+
+/// A list of [`WithdrawalRequest`]s, RLP-encoded as a single list.
+///
+/// Decoding leaves any bytes following the list in the buffer. Use [`alloy_rlp::decode_exact`] to
+/// reject trailing bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodableWrapper, RlpDecodableWrapper)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct WithdrawalRequests(pub Vec<WithdrawalRequest>);
+
+impl From<Vec<WithdrawalRequest>> for WithdrawalRequests {
+    fn from(requests: Vec<WithdrawalRequest>) -> Self {
+        Self(requests)
+    }
+}
+
+impl Deref for WithdrawalRequests {
+    type Target = [WithdrawalRequest];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Aggregate statistics over a batch of [`WithdrawalRequest`]s, e.g. the requests of a block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RequestStats {
+    /// The number of requests.
+    pub count: usize,
+    /// The number of full exit requests, i.e. requests with an amount of zero.
+    pub full_exits: usize,
+    /// The total amount requested, in gwei.
+    pub total_amount: u128,
+    /// The number of distinct source addresses.
+    pub distinct_sources: usize,
+}
+
+impl RequestStats {
+    /// Computes the statistics for the given requests.
+    pub fn from_requests(requests: &[WithdrawalRequest]) -> Self {
+        let sources: BTreeSet<_> = requests.iter().map(|request| request.source_address).collect();
+        Self {
+            count: requests.len(),
+            full_exits: full_exit_count(requests),
+            total_amount: total_requested_gwei(requests),
+            distinct_sources: sources.len(),
+        }
+    }
+}
+
+/// Returns the full exit requests among the given requests, i.e. the requests with an amount of
+/// zero.
+pub fn full_exits(requests: &[WithdrawalRequest]) -> impl Iterator<Item = &WithdrawalRequest> {
+    requests.iter().filter(|request| request.is_full_exit())
+}
+
+/// Returns the partial withdrawal requests among the given requests, i.e. the requests with a
+/// non-zero amount.
+pub fn partial_withdrawals(
+    requests: &[WithdrawalRequest],
+) -> impl Iterator<Item = &WithdrawalRequest> {
+    requests.iter().filter(|request| !request.is_full_exit())
+}
+
+/// Returns the total amount requested by the given requests, in gwei.
+///
+/// The amounts are summed as [`u128`], so the total cannot overflow. Full exits count as zero:
+/// they withdraw the whole balance of the validator, which is only known to the consensus layer
+/// when the exit is processed, not at request time. Use [`full_exit_count`] to account for them
+/// separately.
+pub fn total_requested_gwei(requests: &[WithdrawalRequest]) -> u128 {
+    requests.iter().map(|request| request.amount.0 as u128).sum()
+}
+
+/// Returns the number of full exit requests among the given requests.
+pub fn full_exit_count(requests: &[WithdrawalRequest]) -> usize {
+    full_exits(requests).count()
+}
+
+/// Returns the requests among the given requests whose amount lies within the inclusive range.
+///
+/// An empty range, i.e. one whose start is greater than its end, matches no requests.
+pub fn filter_amount_range(
+    requests: &[WithdrawalRequest],
+    range: RangeInclusive<Gwei>,
+) -> impl Iterator<Item = &WithdrawalRequest> {
+    requests.iter().filter(move |request| range.contains(&request.amount))
+}
+
+/// Compares requests by amount, larger amounts first. Full exits, having an amount of zero, are
+/// ordered last.
+///
+/// For use with [`slice::sort_by`], e.g. by a block builder prioritizing requests. This is a
+/// builder policy only, the consensus order of requests is the order in which the withdrawal
+/// requests contract returns them.
+pub fn by_amount_desc(a: &WithdrawalRequest, b: &WithdrawalRequest) -> Ordering {
+    b.amount.cmp(&a.amount)
+}
+
+/// Compares requests by kind, full exits first. Requests of the same kind compare as equal, so a
+/// stable sort keeps their relative order. Use [`Ordering::then_with`] to break ties, e.g. with
+/// [`by_amount_desc`].
+///
+/// For use with [`slice::sort_by`], e.g. by a block builder prioritizing requests. This is a
+/// builder policy only, the consensus order of requests is the order in which the withdrawal
+/// requests contract returns them.
+pub fn by_full_exit_first(a: &WithdrawalRequest, b: &WithdrawalRequest) -> Ordering {
+    b.is_full_exit().cmp(&a.is_full_exit())
+}
+
+/// Groups the given requests by their source address.
+///
+/// The requests of each source address are kept in their original order.
+pub fn group_by_source(
+    requests: &[WithdrawalRequest],
+) -> BTreeMap<Address, Vec<&WithdrawalRequest>> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for request in requests {
+        groups.entry(request.source_address.0).or_default().push(request);
+    }
+    groups
+}
+
+/// The difference between two sets of [`WithdrawalRequest`]s, as returned by [`diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestDiff {
+    /// The requests only present in the first set.
+    pub only_in_a: Vec<WithdrawalRequest>,
+    /// The requests only present in the second set.
+    pub only_in_b: Vec<WithdrawalRequest>,
+    /// The requests present in both sets.
+    pub in_both: Vec<WithdrawalRequest>,
+}
+
+impl RequestDiff {
+    /// Returns `true` if both sets contain the same requests.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty()
+    }
+}
+
+/// Compares two sets of requests, e.g. the requests of a locally built block against those of a
+/// remote block.
+///
+/// The comparison ignores the order of the requests, and identifies requests by their
+/// [`WithdrawalRequest::validator_key`] and amount. Duplicate requests are matched one to one, so
+/// a request appearing twice in `a` and once in `b` is reported once in each of
+/// [`RequestDiff::in_both`] and [`RequestDiff::only_in_a`]. Within each list, requests are kept in
+/// their original order.
+pub fn diff(a: &[WithdrawalRequest], b: &[WithdrawalRequest]) -> RequestDiff {
+    let mut remaining = BTreeMap::<_, usize>::new();
+    for request in b {
+        *remaining.entry((request.validator_key(), request.amount)).or_default() += 1;
+    }
+
+    let mut result = RequestDiff::default();
+    for request in a {
+        match remaining.get_mut(&(request.validator_key(), request.amount)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                result.in_both.push(*request);
+            }
+            _ => result.only_in_a.push(*request),
+        }
+    }
+
+    // the requests of `b` that were not matched are the last ones of each identity
+    for request in b.iter().rev() {
+        if let Some(count) = remaining.get_mut(&(request.validator_key(), request.amount)) {
+            if *count > 0 {
+                *count -= 1;
+                result.only_in_b.push(*request);
+            }
+        }
+    }
+    result.only_in_b.reverse();
+    result
+}
+
+/// Merges the requests targeting the same validator into a single request.
+///
+/// Requests are identified by their [`WithdrawalRequest::validator_key`]. The amounts of partial
+/// withdrawals are summed, saturating at [`Gwei::MAX`], and a full exit absorbs any other request
+/// for the same validator, so the merged request is a full exit. The merged requests are returned
+/// in the order in which their validator first appears.
+///
+/// This is a block builder policy, not consensus behavior: the withdrawal requests contract and
+/// the consensus layer process every request individually.
+pub fn coalesce(requests: &[WithdrawalRequest]) -> Vec<WithdrawalRequest> {
+    let mut indices = BTreeMap::<_, usize>::new();
+    let mut result = Vec::<WithdrawalRequest>::new();
+    for request in requests {
+        match indices.get(&request.validator_key()) {
+            Some(&index) => {
+                let merged = &mut result[index];
+                merged.amount = if merged.is_full_exit() || request.is_full_exit() {
+                    Gwei::ZERO
+                } else {
+                    merged.amount.saturating_add(request.amount)
+                };
+            }
+            None => {
+                indices.insert(request.validator_key(), result.len());
+                result.push(*request);
+            }
+        }
+    }
+    result
+}
+
+/// Sorts the requests and removes exact duplicates, i.e. requests with the same source address,
+/// validator public key and amount.
+///
+/// The result is deterministic: the requests are ordered by the [`Ord`] implementation of
+/// [`WithdrawalRequest`], whatever their input order. Requests for the same validator with
+/// different amounts are all kept, see [`coalesce`] to merge them.
+///
+/// Like [`coalesce`], this is an off-chain policy, e.g. of a builder that forbids duplicate
+/// requests: the withdrawal requests contract accepts duplicates, and the consensus ordering of
+/// requests is the order in which the contract returns them.
+pub fn sort_dedup(mut requests: Vec<WithdrawalRequest>) -> Vec<WithdrawalRequest> {
+    requests.sort_unstable();
+    requests.dedup();
+    requests
+}
+
+/// Splits the requests into parallel columns of source addresses, validator public keys and
+/// amounts in gwei, e.g. for columnar storage formats such as Arrow or Parquet.
+///
+/// This is the inverse of [`from_columns`].
+pub fn to_columns(requests: &[WithdrawalRequest]) -> (Vec<Address>, Vec<FixedBytes<48>>, Vec<u64>) {
+    let mut source_addresses = Vec::with_capacity(requests.len());
+    let mut validator_pubkeys = Vec::with_capacity(requests.len());
+    let mut amounts = Vec::with_capacity(requests.len());
+    for request in requests {
+        source_addresses.push(request.source_address.0);
+        validator_pubkeys.push(request.validator_pubkey);
+        amounts.push(request.amount.0);
+    }
+    (source_addresses, validator_pubkeys, amounts)
+}
+
+/// Reassembles requests from parallel columns, as returned by [`to_columns`].
+///
+/// Returns [`Eip7002DecodeError::UnexpectedLength`] if the columns do not all have the length of
+/// the source addresses column.
+pub fn from_columns(
+    source_addresses: &[Address],
+    validator_pubkeys: &[FixedBytes<48>],
+    amounts: &[u64],
+) -> Result<Vec<WithdrawalRequest>, Eip7002DecodeError> {
+    let expected = source_addresses.len();
+    for got in [validator_pubkeys.len(), amounts.len()] {
+        if got != expected {
+            return Err(Eip7002DecodeError::UnexpectedLength { expected, got });
+        }
+    }
+    Ok(source_addresses
+        .iter()
+        .zip(validator_pubkeys)
+        .zip(amounts)
+        .map(|((&source_address, &validator_pubkey), &amount)| WithdrawalRequest {
+            source_address: SourceAddress(source_address),
+            validator_pubkey,
+            amount: Gwei(amount),
+        })
+        .collect())
+}
+
+/// Resolves the validator index of each request from a registry of validator public keys.
+///
+/// Returns `None` for requests whose validator public key is not in the registry.
+#[cfg(feature = "std")]
+pub fn resolve_indices<S: std::hash::BuildHasher>(
+    requests: &[WithdrawalRequest],
+    registry: &std::collections::HashMap<FixedBytes<48>, u64, S>,
+) -> Vec<Option<u64>> {
+    requests.iter().map(|request| registry.get(&request.validator_pubkey).copied()).collect()
+}
+
+/// Returns the genesis allocation for the EIP-7002 withdrawal requests contract.
+///
+/// The account is deployed at [`WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS`] with
+/// [`WITHDRAWAL_REQUEST_PREDEPLOY_CODE`], a nonce of 1, no balance and empty storage.
+#[cfg(feature = "genesis")]
+pub fn genesis_account() -> (Address, alloy_genesis::GenesisAccount) {
+    let account = alloy_genesis::GenesisAccount::default()
+        .with_nonce(Some(1))
+        .with_code(Some(WITHDRAWAL_REQUEST_PREDEPLOY_CODE.clone()));
+    (WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS, account)
+}
+
+/// Errors that can occur when constructing a [`WithdrawalRequest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WithdrawalRequestError {
+    /// The validator public key is all zeroes.
+    ZeroValidatorPubkey,
+    /// The amount is [`u64::MAX`], which is reserved as a sentinel value.
+    SentinelAmount,
+    /// The amount in wei is not a whole number of gwei.
+    FractionalGweiAmount,
+    /// The amount in gwei does not fit in a [`u64`].
+    AmountOverflow,
+    /// The amount is at least [`MAX_PLAUSIBLE_GWEI_AMOUNT`], and most likely denominated in wei.
+    ImplausibleAmount,
+}
+
+impl fmt::Display for WithdrawalRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroValidatorPubkey => f.write_str("validator public key is all zeroes"),
+            Self::SentinelAmount => f.write_str("amount must not be u64::MAX"),
+            Self::FractionalGweiAmount => {
+                f.write_str("amount in wei is not a whole number of gwei")
+            }
+            Self::AmountOverflow => f.write_str("amount in gwei does not fit in a u64"),
+            Self::ImplausibleAmount => f.write_str("amount is not a plausible gwei value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WithdrawalRequestError {}
+
+/// Errors that can occur when decoding [`WithdrawalRequest`]s, e.g. from the packed output of the
+/// EIP-7002 withdrawal requests contract.
+///
+/// This type only relies on [`core`], so it is available in `no_std` environments. The
+/// `std::error::Error` implementation requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_eips::eip7002::{Eip7002DecodeError, WithdrawalRequest};
+/// use core::fmt::Write;
+///
+/// let err = WithdrawalRequest::decode_system_contract_output(&[0; 75]).unwrap_err();
+/// assert_eq!(err, Eip7002DecodeError::InvalidLength(75));
+///
+/// let mut msg = String::new();
+/// write!(msg, "{err}").unwrap();
+/// assert!(msg.starts_with("invalid withdrawal requests output length 75"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Eip7002DecodeError {
+    /// The length of the output is not a multiple of [`WITHDRAWAL_REQUEST_PACKED_SIZE`].
+    InvalidLength(usize),
+    /// The input does not have the expected length.
+    UnexpectedLength {
+        /// The expected length.
+        expected: usize,
+        /// The actual length.
+        got: usize,
+    },
+    /// The log topics do not match [`WITHDRAWAL_REQUEST_LOG_TOPIC`].
+    InvalidLogTopics,
+    /// The input is not valid hex.
+    Hex(hex::FromHexError),
+    /// The input is not a valid RLP-encoded request.
+    Rlp(alloy_rlp::Error),
+    /// The ABI tokens are not an `(address, bytes, uint64)` tuple.
+    InvalidAbiTokens,
+    /// The input starts with a request type other than [`WITHDRAWAL_REQUEST_TYPE`].
+    BadTypePrefix(u8),
+    /// The input is empty.
+    EmptyInput,
+    /// The output holds more than [`MAX_WITHDRAWAL_REQUESTS_PER_BLOCK`] requests.
+    TooManyRequests(usize),
+}
+
+impl fmt::Display for Eip7002DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(
+                f,
+                "invalid withdrawal requests output length {len}, expected a multiple of {WITHDRAWAL_REQUEST_PACKED_SIZE}"
+            ),
+            Self::UnexpectedLength { expected, got } => {
+                write!(f, "unexpected length {got}, expected {expected}")
+            }
+            Self::InvalidLogTopics => f.write_str("invalid withdrawal request log topics"),
+            Self::Hex(err) => write!(f, "invalid hex: {err}"),
+            Self::Rlp(err) => write!(f, "invalid withdrawal request RLP: {err}"),
+            Self::InvalidAbiTokens => {
+                f.write_str("invalid withdrawal request ABI tokens, expected (address, bytes, uint64)")
+            }
+            Self::BadTypePrefix(ty) => write!(
+                f,
+                "unexpected request type {ty}, expected {WITHDRAWAL_REQUEST_TYPE}"
+            ),
+            Self::EmptyInput => f.write_str("empty withdrawal request input"),
+            Self::TooManyRequests(count) => write!(
+                f,
+                "too many withdrawal requests: {count}, expected at most {MAX_WITHDRAWAL_REQUESTS_PER_BLOCK}"
+            ),
+        }
+    }
+}
+
+impl From<hex::FromHexError> for Eip7002DecodeError {
+    fn from(err: hex::FromHexError) -> Self {
+        Self::Hex(err)
+    }
+}
+
+impl From<alloy_rlp::Error> for Eip7002DecodeError {
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::Rlp(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip7002DecodeError {}
+
+/// Returns the withdrawal request shared by the tests of this crate.
+#[cfg(test)]
+pub(crate) const fn sample_request() -> WithdrawalRequest {
+    WithdrawalRequest {
+        source_address: SourceAddress(alloy_primitives::address!(
+            "AE0E8770147AaA6828a0D6f642504663F10F7d1E"
+        )),
+        validator_pubkey: alloy_primitives::fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"),
+        amount: Gwei(354),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::fixed_bytes;
+
+    const PUBKEY: FixedBytes<48> = sample_request().validator_pubkey;
+
+    #[test]
+    fn predeploy_code_hash() {
+        assert_eq!(
+            alloy_primitives::keccak256(&WITHDRAWAL_REQUEST_PREDEPLOY_CODE),
+            WITHDRAWAL_REQUEST_PREDEPLOY_CODE_HASH
+        );
+    }
+
+    #[test]
+    fn system_contract_consts() {
+        const CONTRACT: (Address, &[u8], B256) = (
+            WithdrawalRequestContract::ADDRESS,
+            WithdrawalRequestContract::CODE,
+            WithdrawalRequestContract::CODE_HASH,
+        );
+        assert_eq!(CONTRACT.0, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS);
+        assert_eq!(CONTRACT.1, &WITHDRAWAL_REQUEST_PREDEPLOY_CODE[..]);
+        assert_eq!(alloy_primitives::keccak256(CONTRACT.1), CONTRACT.2);
+    }
+
+    #[test]
+    #[cfg(feature = "genesis")]
+    fn genesis_account_code_hash() {
+        let (address, account) = genesis_account();
+        assert_eq!(address, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS);
+        assert_eq!(account.nonce, Some(1));
+        assert!(account.storage.is_none());
+        assert_eq!(
+            alloy_primitives::keccak256(account.code.unwrap()),
+            WITHDRAWAL_REQUEST_PREDEPLOY_CODE_HASH
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ssz")]
+    fn ssz_tree_hash_root() {
+        let request = sample_request();
+        assert_eq!(
+            request.tree_hash_root(),
+            b256!("f06c2f6ce063edb33056a7dabbc0efb1a6662ca47826e3785cb0193af5a97ec1")
+        );
+    }
+
+    #[test]
+    fn withdrawal_request_fee() {
+        // computed with the `fake_exponential` reference implementation from the EIP
+        assert_eq!(fee(0), 1);
+        assert_eq!(fee(1), 1);
+        assert_eq!(fee(17), 2);
+        assert_eq!(fee(34), 7);
+        assert_eq!(fee(100), 357);
+        assert_eq!(fee(500), 5933467376577);
+        assert_eq!(fee(1000), 35214595411832335374580891);
+        assert_eq!(fee(1500), 208976784644275015522727291300813628526);
+
+        assert_eq!(fee(2000), u128::MAX);
+        assert_eq!(fee(u64::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn withdrawal_request_required_fee_wei() {
+        assert_eq!(required_fee_wei(0), U256::from(MIN_WITHDRAWAL_REQUEST_FEE));
+        assert_eq!(required_fee_wei(17), U256::from(2));
+        assert_eq!(required_fee_wei(100), U256::from(357));
+        assert_eq!(required_fee_wei(1000), U256::from(35214595411832335374580891u128));
+        assert_eq!(required_fee_wei(u64::MAX), U256::from(u128::MAX));
+    }
+
+    #[test]
+    fn withdrawal_request_fee_from_excess_slot() {
+        assert_eq!(fee_from_excess_slot(U256::ZERO), Some(1));
+        assert_eq!(fee_from_excess_slot(U256::from(100)), Some(357));
+        assert_eq!(fee_from_excess_slot(EXCESS_INHIBITOR), None);
+        assert_eq!(fee_from_excess_slot(U256::from(u64::MAX) + U256::from(1)), None);
+    }
+
+    #[test]
+    fn try_new_valid() {
+        let source_address = SourceAddress(address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"));
+        let request = WithdrawalRequest::try_new(source_address, PUBKEY, Gwei(354)).unwrap();
+        assert_eq!(
+            request,
+            WithdrawalRequest { source_address, validator_pubkey: PUBKEY, amount: Gwei(354) }
+        );
+
+        // a full exit is a valid request
+        assert!(WithdrawalRequest::try_new(source_address, PUBKEY, Gwei::ZERO).is_ok());
+    }
+
+    #[test]
+    fn validate_invariants() {
+        let request = sample_request();
+        assert_eq!(request.validate(), Ok(()));
+        assert_eq!(WithdrawalRequest { amount: Gwei::ZERO, ..request }.validate(), Ok(()));
+        assert_eq!(
+            WithdrawalRequest { amount: Gwei(MAX_PLAUSIBLE_GWEI_AMOUNT - 1), ..request }.validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            WithdrawalRequest { validator_pubkey: FixedBytes::ZERO, ..request }.validate(),
+            Err(WithdrawalRequestError::ZeroValidatorPubkey)
+        );
+        assert_eq!(
+            WithdrawalRequest { amount: Gwei::MAX, ..request }.validate(),
+            Err(WithdrawalRequestError::SentinelAmount)
+        );
+
+        // implausible amounts are allowed by the protocol, and only rejected by the lint
+        let implausible = WithdrawalRequest { amount: Gwei(MAX_PLAUSIBLE_GWEI_AMOUNT), ..request };
+        assert_eq!(implausible.validate(), Ok(()));
+        assert_eq!(
+            implausible.check_plausible_amount(),
+            Err(WithdrawalRequestError::ImplausibleAmount)
+        );
+        assert_eq!(request.check_plausible_amount(), Ok(()));
+        assert_eq!(
+            WithdrawalRequest { amount: Gwei(MAX_PLAUSIBLE_GWEI_AMOUNT - 1), ..request }
+                .check_plausible_amount(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn try_new_zero_pubkey() {
+        assert_eq!(
+            WithdrawalRequest::try_new(SourceAddress(Address::ZERO), FixedBytes::ZERO, Gwei(354)),
+            Err(WithdrawalRequestError::ZeroValidatorPubkey)
+        );
+    }
+
+    #[test]
+    fn try_new_sentinel_amount() {
+        assert_eq!(
+            WithdrawalRequest::try_new(SourceAddress(Address::ZERO), PUBKEY, Gwei::MAX),
+            Err(WithdrawalRequestError::SentinelAmount)
+        );
+    }
+
+    #[test]
+    fn amount_be_bytes() {
+        let request = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        assert_eq!(request.amount_be_bytes(), 354u64.to_be_bytes());
+        assert_eq!(request.amount_be_bytes(), request.to_compact()[68..]);
+        assert_eq!(
+            WithdrawalRequest::default().with_amount_be_bytes(354u64.to_be_bytes()),
+            request
+        );
+    }
+
+    #[test]
+    fn amount_wei() {
+        let mut request =
+            WithdrawalRequest { amount: Gwei(MAX_PLAUSIBLE_GWEI_AMOUNT - 1), ..Default::default() };
+        let amount_wei = request.amount_wei();
+        assert_eq!(amount_wei, U256::from(MAX_PLAUSIBLE_GWEI_AMOUNT - 1) * U256::from(GWEI_TO_WEI));
+        assert_eq!(amount_wei.to_string(), "1152921504606846975000000000");
+
+        let amount_wei = U256::from(u64::MAX) * U256::from(GWEI_TO_WEI);
+        request.amount = Gwei::ZERO;
+        request.set_amount_wei(amount_wei).unwrap();
+        assert_eq!(request.amount, Gwei::MAX);
+
+        request.set_amount_wei(U256::from(354) * U256::from(GWEI_TO_WEI)).unwrap();
+        assert_eq!(request.amount, Gwei(354));
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema() {
+        let schema: serde_json::Value =
+            serde_json::from_str(&WithdrawalRequest::json_schema_string()).unwrap();
+        assert_eq!(schema["title"], "WithdrawalRequest");
+        assert_eq!(schema["required"], serde_json::json!(["amount"]));
+        // unknown fields are ignored when deserializing
+        assert!(schema.get("additionalProperties").is_none());
+
+        let properties = &schema["properties"];
+        assert_eq!(properties["sourceAddress"]["format"], "address");
+        assert_eq!(properties["validatorPubkey"]["format"], "bytes48");
+        assert_eq!(properties["validatorPubkey"]["pattern"], "^(0[xX])?[0-9a-fA-F]{96}$");
+        assert_eq!(properties["amount"]["format"], "quantity");
+        for (alias, field) in
+            [("source_address", "sourceAddress"), ("validator_pubkey", "validatorPubkey")]
+        {
+            assert_eq!(properties[alias], properties[field]);
+            let request = WithdrawalRequest::default();
+            let mut json = serde_json::to_value(request).unwrap();
+            let object = json.as_object_mut().unwrap();
+            let value = object.remove(field).unwrap();
+            object.insert(alias.into(), value);
+            assert_eq!(serde_json::from_value::<WithdrawalRequest>(json).unwrap(), request);
+        }
+
+        // every accepted form of the amount is described
+        let amount = properties["amount"]["anyOf"].as_array().unwrap();
+        assert_eq!(amount[0]["pattern"], "^0[xX][0-9a-fA-F]*$");
+        assert_eq!(amount[1]["pattern"], "^[0-9]+$");
+        assert_eq!(amount[2]["maximum"], u64::MAX);
+        for amount in [r#""0x162""#, r#""354""#, "354"] {
+            assert_eq!(serde_json::from_str::<Gwei>(amount).unwrap(), Gwei(354));
+        }
+
+        let request = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let json = serde_json::to_value(request).unwrap();
+        for key in json.as_object().unwrap().keys() {
+            assert!(properties.get(key).is_some(), "{key} is missing from the schema");
+        }
+    }
+
+    #[test]
+    fn amount_wei_implausible_amount() {
+        // 10 ether in wei, stored in the gwei field, is still valid chain data
+        let request = WithdrawalRequest {
+            amount: Gwei(10 * GWEI_TO_WEI * GWEI_TO_WEI),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.amount_wei(),
+            U256::from(10 * GWEI_TO_WEI * GWEI_TO_WEI) * U256::from(GWEI_TO_WEI)
+        );
+        assert_eq!(
+            request.check_plausible_amount(),
+            Err(WithdrawalRequestError::ImplausibleAmount)
+        );
+
+        let request = WithdrawalRequest { amount: Gwei::MAX, ..Default::default() };
+        assert_eq!(request.amount_wei(), U256::from(u64::MAX) * U256::from(GWEI_TO_WEI));
+    }
+
+    #[test]
+    fn amount_ether_f64() {
+        let request = WithdrawalRequest { amount: Gwei(32_000_000_000), ..Default::default() };
+        assert_eq!(request.amount_ether_f64(), 32.0);
+        let request = WithdrawalRequest { amount: Gwei(1_500_000_354), ..Default::default() };
+        assert!((request.amount_ether_f64() - 1.500_000_354).abs() < 1e-12);
+        assert_eq!(WithdrawalRequest::default().amount_ether_f64(), 0.0);
+    }
+
+    #[test]
+    fn set_amount_wei_invalid() {
+        let mut request = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        assert_eq!(
+            request.set_amount_wei(U256::from(GWEI_TO_WEI + 1)),
+            Err(WithdrawalRequestError::FractionalGweiAmount)
+        );
+        assert_eq!(
+            request
+                .set_amount_wei((U256::from(u64::MAX) + U256::from(1)) * U256::from(GWEI_TO_WEI)),
+            Err(WithdrawalRequestError::AmountOverflow)
+        );
+        assert_eq!(request.amount, Gwei(354));
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn ct_eq_agrees_with_eq() {
+        let request = sample_request();
+        let others = [
+            request,
+            WithdrawalRequest { source_address: SourceAddress(Address::ZERO), ..request },
+            WithdrawalRequest { validator_pubkey: FixedBytes::ZERO, ..request },
+            WithdrawalRequest { amount: Gwei(355), ..request },
+        ];
+        for other in others {
+            assert_eq!(bool::from(request.ct_eq(&other)), request == other);
+        }
+    }
+
+    #[test]
+    fn arbitrary_bounded_amount() {
+        use arbitrary::Arbitrary;
+
+        for _ in 0..256 {
+            let bytes = FixedBytes::<128>::random();
+            let mut u = arbitrary::Unstructured::new(bytes.as_slice());
+            let request = ArbitraryBoundedAmount::arbitrary(&mut u).unwrap().0;
+            assert!(request.amount <= Gwei(crate::eip7251::MAX_EFFECTIVE_BALANCE));
+        }
+    }
+
+    #[test]
+    fn decode_system_contract_output_roundtrip() {
+        let requests = [
+            sample_request(),
+            WithdrawalRequest {
+                source_address: SourceAddress(address!("e4D06cD23F6e64A1C671b3cd4d4B8F2C66B4D8a2")),
+                validator_pubkey: FixedBytes::repeat_byte(0x11),
+                amount: Gwei::ZERO,
+            },
+            WithdrawalRequest {
+                source_address: SourceAddress(Address::ZERO),
+                validator_pubkey: FixedBytes::repeat_byte(0xff),
+                amount: Gwei(u64::MAX - 1),
+            },
+        ];
+
+        let output = WithdrawalRequest::encode_system_contract_output(&requests);
+        assert_eq!(output.len(), requests.len() * WITHDRAWAL_REQUEST_PACKED_SIZE);
+
+        let decoded = WithdrawalRequest::decode_system_contract_output(&output).unwrap();
+        assert_eq!(decoded, requests);
+
+        assert_eq!(WithdrawalRequest::decode_system_contract_output(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn decode_system_contract_output_invalid_length() {
+        let output = [0u8; WITHDRAWAL_REQUEST_PACKED_SIZE + 1];
+        assert_eq!(
+            WithdrawalRequest::decode_system_contract_output(&output),
+            Err(Eip7002DecodeError::InvalidLength(WITHDRAWAL_REQUEST_PACKED_SIZE + 1))
+        );
+    }
+
+    #[test]
+    fn decode_untrusted_system_output() {
+        let max = MAX_WITHDRAWAL_REQUESTS_PER_BLOCK as usize;
+        let requests: Vec<_> = (0..=max)
+            .map(|i| WithdrawalRequest { amount: Gwei(i as u64), ..Default::default() })
+            .collect();
+        let output = WithdrawalRequest::encode_system_contract_output(&requests);
+
+        assert_eq!(
+            WithdrawalRequest::decode_untrusted_system_output(
+                &output[..max * WITHDRAWAL_REQUEST_PACKED_SIZE]
+            ),
+            Ok(requests[..max].to_vec())
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_untrusted_system_output(&output),
+            Err(Eip7002DecodeError::TooManyRequests(max + 1))
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_untrusted_system_output(&output[1..]),
+            Err(Eip7002DecodeError::InvalidLength(output.len() - 1))
+        );
+        assert_eq!(WithdrawalRequest::decode_untrusted_system_output(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn count_in_system_output() {
+        let output = [0u8; 3 * WITHDRAWAL_REQUEST_PACKED_SIZE];
+        assert_eq!(WithdrawalRequest::count_in_system_output(&output), Ok(3));
+        assert_eq!(WithdrawalRequest::count_in_system_output(&[]), Ok(0));
+        assert_eq!(
+            WithdrawalRequest::count_in_system_output(&output[..WITHDRAWAL_REQUEST_PACKED_SIZE]),
+            Ok(1)
+        );
+
+        for len in [1, WITHDRAWAL_REQUEST_PACKED_SIZE - 1, 2 * WITHDRAWAL_REQUEST_PACKED_SIZE + 1] {
+            assert_eq!(
+                WithdrawalRequest::count_in_system_output(&output[..len]),
+                Err(Eip7002DecodeError::InvalidLength(len))
+            );
+        }
+    }
+
+    #[test]
+    fn iter_system_contract_output() {
+        let requests: Vec<_> = (0..3)
+            .map(|i| WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(i)),
+                validator_pubkey: PUBKEY,
+                amount: Gwei(i as u64),
+            })
+            .collect();
+        let output = WithdrawalRequest::encode_system_contract_output(&requests);
+
+        let iter = WithdrawalRequest::iter_system_contract_output(&output);
+        assert_eq!(iter.len(), 3);
+        let decoded = iter.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(decoded, WithdrawalRequest::decode_system_contract_output(&output).unwrap());
+        assert_eq!(decoded, requests);
+
+        assert_eq!(WithdrawalRequest::iter_system_contract_output(&[]).next(), None);
+
+        // both entry points agree on malformed output as well
+        for len in [1, WITHDRAWAL_REQUEST_PACKED_SIZE - 1, output.len() - 1, output.len() + 1] {
+            let output = [&output[..], &[0]].concat();
+            let output = &output[..len];
+            assert_eq!(
+                WithdrawalRequest::iter_system_contract_output(output)
+                    .collect::<Result<Vec<_>, _>>(),
+                WithdrawalRequest::decode_system_contract_output(output)
+            );
+        }
+    }
+
+    #[test]
+    fn iter_system_contract_output_truncated() {
+        let request = WithdrawalRequest { validator_pubkey: PUBKEY, ..Default::default() };
+        let output = WithdrawalRequest::encode_system_contract_output(&[request, request]);
+        let truncated = &output[..output.len() - 1];
+
+        let mut iter = WithdrawalRequest::iter_system_contract_output(truncated);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(Err(Eip7002DecodeError::InvalidLength(truncated.len()))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn encode_system_contract_output_layout() {
+        let request = sample_request();
+        let output = WithdrawalRequest::encode_system_contract_output(&[request]);
+        assert_eq!(&output[..20], request.source_address.0.as_slice());
+        assert_eq!(&output[20..68], PUBKEY.as_slice());
+        assert_eq!(&output[68..], &354u64.to_be_bytes());
+    }
+
+    #[test]
+    fn compact_roundtrip() {
+        let request = sample_request();
+        let compact = request.to_compact();
+        assert_eq!(&compact[..], &WithdrawalRequest::encode_system_contract_output(&[request])[..]);
+        assert_eq!(WithdrawalRequest::from_compact(&compact), request);
+    }
+
+    #[test]
+    fn try_from_packed_slice() {
+        let request = sample_request();
+        let compact = request.to_compact();
+        assert_eq!(WithdrawalRequest::try_from(&compact[..]), Ok(request));
+
+        assert_eq!(
+            WithdrawalRequest::try_from(&compact[..75]),
+            Err(Eip7002DecodeError::UnexpectedLength { expected: 76, got: 75 })
+        );
+        assert_eq!(
+            WithdrawalRequest::try_from(&[compact.as_slice(), &[0]].concat()[..]),
+            Err(Eip7002DecodeError::UnexpectedLength { expected: 76, got: 77 })
+        );
+    }
+
+    #[test]
+    fn arbitrary_system_contract_output_roundtrip() {
+        for _ in 0..16 {
+            let bytes = FixedBytes::<1024>::random();
+            let requests: Vec<WithdrawalRequest> =
+                arbitrary::Unstructured::new(bytes.as_slice()).arbitrary().unwrap();
+
+            let output = WithdrawalRequest::encode_system_contract_output(&requests);
+            assert_eq!(WithdrawalRequest::decode_system_contract_output(&output), Ok(requests));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_withdrawal_request() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#;
+
+        let request: WithdrawalRequest = serde_json::from_str(input).unwrap();
+        assert_eq!(request, sample_request());
+        assert_eq!(serde_json::to_string(&request).unwrap(), input);
+
+        let full_exit = WithdrawalRequest { amount: Gwei::ZERO, ..request };
+        assert!(serde_json::to_string(&full_exit).unwrap().contains(r#""amount":"0x0""#));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_withdrawal_request_decimal_amount() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"354"}"#;
+        let request: WithdrawalRequest = serde_json::from_str(input).unwrap();
+        assert_eq!(request.amount, Gwei(354));
+
+        // re-serializing always produces the hex form
+        assert!(serde_json::to_string(&request).unwrap().contains(r#""amount":"0x162""#));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_withdrawal_request_snake_case() {
+        let camel_case = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#;
+        let snake_case = r#"{"source_address":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validator_pubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#;
+
+        let request: WithdrawalRequest = serde_json::from_str(camel_case).unwrap();
+        assert_eq!(serde_json::from_str::<WithdrawalRequest>(snake_case).unwrap(), request);
+        // serialization always uses camelCase
+        assert_eq!(serde_json::to_string(&request).unwrap(), camel_case);
+
+        // the pubkey length is still validated for the snake_case spelling
+        let short_pubkey = snake_case.replace("3cb8793b", "");
+        assert!(serde_json::from_str::<WithdrawalRequest>(&short_pubkey).is_err());
+    }
+
+    // The serde field order is part of the stable binary format. Changing it breaks requests stored
+    // with non-self-describing formats such as bincode, and requires a version bump.
+    #[test]
+    #[cfg(feature = "serde")]
+    fn bincode_stable_field_order() {
+        let request = sample_request();
+        let encoded = bincode::serialize(&request).unwrap();
+        // each field is a length-prefixed byte sequence: source address, pubkey, and the amount
+        // as a big-endian `U64`
+        assert_eq!(
+            encoded,
+            hex!("1400000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e30000000000000008e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b08000000000000000000000000000162")
+        );
+        assert_eq!(bincode::deserialize::<WithdrawalRequest>(&encoded).unwrap(), request);
+    }
+
+    #[test]
+    #[cfg(feature = "bls")]
+    fn arbitrary_valid_pubkey() {
+        for _ in 0..16 {
+            let bytes = FixedBytes::<128>::random();
+            let request = WithdrawalRequest::arbitrary_valid(&mut arbitrary::Unstructured::new(
+                bytes.as_slice(),
+            ))
+            .unwrap();
+            let pubkey = request.validator_pubkey_g1().unwrap();
+            assert_eq!(pubkey.compress(), request.validator_pubkey.0);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bls")]
+    fn validator_pubkey_g1() {
+        let request = WithdrawalRequest { validator_pubkey: PUBKEY, ..Default::default() };
+        assert_eq!(request.validator_pubkey_g1().unwrap().compress(), PUBKEY.0);
+
+        // missing compression flag
+        let request = WithdrawalRequest::default();
+        assert_eq!(request.validator_pubkey_g1(), Err(blst::BLST_ERROR::BLST_BAD_ENCODING));
+
+        // point at infinity
+        let mut infinity = FixedBytes::<48>::ZERO;
+        infinity[0] = 0xc0;
+        let request = WithdrawalRequest { validator_pubkey: infinity, ..Default::default() };
+        assert_eq!(request.validator_pubkey_g1(), Err(blst::BLST_ERROR::BLST_PK_IS_INFINITY));
+    }
+
+    #[test]
+    fn predeploy_calldata() {
+        let request = sample_request();
+        let calldata = request.to_predeploy_calldata();
+        assert_eq!(calldata.len(), WITHDRAWAL_REQUEST_CALLDATA_SIZE);
+        assert_eq!(calldata[..48], PUBKEY[..]);
+        assert_eq!(calldata[48..], [0, 0, 0, 0, 0, 0, 0x01, 0x62]);
+
+        // the calldata is the packed request without its source address
+        assert_eq!(calldata[..], request.to_compact()[20..]);
+        let full_exit = WithdrawalRequest { amount: Gwei::ZERO, ..request };
+        assert_eq!(full_exit.to_predeploy_calldata()[48..], [0; 8]);
+    }
+
+    #[test]
+    fn from_queue_slots() {
+        let request = sample_request();
+
+        // the words stored by the contract: the caller, then the calldata in 32-byte words
+        let word0 = b256!("000000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e");
+        let word1 = b256!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280");
+        let word2 = b256!("e7f40d2271bad65d8cbbfdd43cb8793b00000000000001620000000000000000");
+        assert_eq!(WithdrawalRequest::from_queue_slots(word0, word1, word2), request);
+        assert_eq!(word0, request.source_address.0.into_word());
+
+        // the calldata is the two last words, up to the padding
+        let calldata = request.to_predeploy_calldata();
+        assert_eq!(word1[..], calldata[..32]);
+        assert_eq!(word2[..24], calldata[32..]);
+    }
+
+    #[test]
+    fn log_roundtrip() {
+        assert_eq!(
+            WITHDRAWAL_REQUEST_LOG_TOPIC,
+            alloy_primitives::keccak256(WITHDRAWAL_REQUEST_LOG_SIGNATURE)
+        );
+
+        let request = sample_request();
+        let log = request.to_log();
+        assert_eq!(log.address, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS);
+        assert_eq!(log.topics(), [WITHDRAWAL_REQUEST_LOG_TOPIC]);
+        assert_eq!(WithdrawalRequest::try_from_log(&log), Ok(request));
+    }
+
+    #[test]
+    fn try_from_log_invalid() {
+        let log = WithdrawalRequest::default().to_log();
+
+        let mut bad_topics = log.clone();
+        bad_topics.data = LogData::new_unchecked(vec![B256::ZERO], log.data.data.clone());
+        assert_eq!(
+            WithdrawalRequest::try_from_log(&bad_topics),
+            Err(Eip7002DecodeError::InvalidLogTopics)
+        );
+
+        let mut bad_data = log;
+        bad_data.data = LogData::new_unchecked(vec![WITHDRAWAL_REQUEST_LOG_TOPIC], Bytes::new());
+        assert_eq!(
+            WithdrawalRequest::try_from_log(&bad_data),
+            Err(Eip7002DecodeError::UnexpectedLength {
+                expected: WITHDRAWAL_REQUEST_PACKED_SIZE,
+                got: 0
+            })
+        );
+    }
+
+    #[test]
+    fn sort_withdrawal_requests() {
+        let low = address!("0000000000000000000000000000000000000001");
+        let high = address!("ff00000000000000000000000000000000000000");
+        let sorted: Vec<WithdrawalRequest> = vec![
+            (low, FixedBytes::ZERO, 7).into(),
+            (low, PUBKEY, 1).into(),
+            (low, PUBKEY, 2).into(),
+            (high, FixedBytes::ZERO, 0).into(),
+            (high, PUBKEY, 0).into(),
+        ];
+
+        let mut requests = vec![sorted[3], sorted[1], sorted[4], sorted[0], sorted[2]];
+        requests.sort();
+        assert_eq!(requests, sorted);
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let source_address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let request = WithdrawalRequest::from((source_address, PUBKEY, 354));
+        assert_eq!(request, sample_request());
+        assert_eq!(<(Address, FixedBytes<48>, u64)>::from(request), (source_address, PUBKEY, 354));
+    }
+
+    #[test]
+    fn display_withdrawal_request() {
+        let request = sample_request();
+        assert_eq!(
+            request.to_string(),
+            "withdrawal(source=0xae0e…7d1e, pubkey=0x8e8d…793b, amount=354 gwei)"
+        );
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let request = sample_request();
+        let encoded = request.to_hex();
+        assert_eq!(encoded, "0xf84994ae0e8770147aaa6828a0d6f642504663f10f7d1eb08e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b820162");
+        assert_eq!(WithdrawalRequest::from_hex(&encoded), Ok(request));
+        assert_eq!(WithdrawalRequest::from_hex(&encoded[2..]), Ok(request));
+    }
+
+    #[test]
+    fn write_hex_fixed_capacity() {
+        /// A fixed-capacity string buffer.
+        struct ArrayString {
+            buf: [u8; 2 + 2 * WithdrawalRequest::MAX_ENCODED_LEN],
+            len: usize,
+        }
+
+        impl fmt::Write for ArrayString {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buf.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        for request in [
+            WithdrawalRequest::default(),
+            WithdrawalRequest { validator_pubkey: PUBKEY, amount: Gwei(354), ..Default::default() },
+            WithdrawalRequest { amount: Gwei::MAX, ..Default::default() },
+        ] {
+            let mut out =
+                ArrayString { buf: [0; 2 + 2 * WithdrawalRequest::MAX_ENCODED_LEN], len: 0 };
+            request.write_hex(&mut out).unwrap();
+            assert_eq!(core::str::from_utf8(&out.buf[..out.len]).unwrap(), request.to_hex());
+        }
+
+        // writers that run out of capacity report an error
+        let mut out =
+            ArrayString { buf: [0; 2 + 2 * WithdrawalRequest::MAX_ENCODED_LEN], len: 100 };
+        assert_eq!(WithdrawalRequest::default().write_hex(&mut out), Err(fmt::Error));
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        assert_eq!(
+            WithdrawalRequest::from_hex("0xzz"),
+            Err(Eip7002DecodeError::Hex(hex::FromHexError::InvalidHexCharacter {
+                c: 'z',
+                index: 0
+            }))
+        );
+        let encoded = WithdrawalRequest::default().to_hex();
+        assert_eq!(
+            WithdrawalRequest::from_hex(&format!("{encoded}00")),
+            Err(Eip7002DecodeError::Rlp(alloy_rlp::Error::UnexpectedLength))
+        );
+        assert_eq!(WithdrawalRequest::from_hex("0x"), Err(Eip7002DecodeError::EmptyInput));
+    }
+
+    #[test]
+    fn decode_error_variants() {
+        let mut encoded = Vec::new();
+        WithdrawalRequest::default().encode_request(&mut encoded);
+
+        assert_eq!(
+            WithdrawalRequest::try_from(&[0; WITHDRAWAL_REQUEST_PACKED_SIZE - 1][..]),
+            Err(Eip7002DecodeError::UnexpectedLength {
+                expected: WITHDRAWAL_REQUEST_PACKED_SIZE,
+                got: WITHDRAWAL_REQUEST_PACKED_SIZE - 1
+            })
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_request(&mut &encoded[..encoded.len() - 1]),
+            Err(Eip7002DecodeError::Rlp(alloy_rlp::Error::InputTooShort))
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_request(&mut &encoded[1..]),
+            Err(Eip7002DecodeError::BadTypePrefix(encoded[1]))
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_request(&mut &[][..]),
+            Err(Eip7002DecodeError::EmptyInput)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dyn-abi")]
+    fn from_abi_tokens() {
+        use alloy_dyn_abi::DynSolValue;
+
+        let source_address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let mut tokens = vec![
+            DynSolValue::Address(source_address),
+            DynSolValue::Bytes(PUBKEY.to_vec()),
+            DynSolValue::Uint(U256::from(354), 64),
+        ];
+        assert_eq!(WithdrawalRequest::from_abi_tokens(&tokens), Ok(sample_request()));
+
+        tokens[1] = DynSolValue::Bytes(PUBKEY[..47].to_vec());
+        assert_eq!(
+            WithdrawalRequest::from_abi_tokens(&tokens),
+            Err(Eip7002DecodeError::UnexpectedLength { expected: 48, got: 47 })
+        );
+
+        tokens[1] = DynSolValue::FixedBytes(B256::ZERO, 32);
+        assert_eq!(
+            WithdrawalRequest::from_abi_tokens(&tokens),
+            Err(Eip7002DecodeError::InvalidAbiTokens)
+        );
+
+        tokens[1] = DynSolValue::Bytes(PUBKEY.to_vec());
+        tokens[2] = DynSolValue::Uint(U256::from(354), 256);
+        assert_eq!(
+            WithdrawalRequest::from_abi_tokens(&tokens),
+            Err(Eip7002DecodeError::InvalidAbiTokens)
+        );
+        assert_eq!(
+            WithdrawalRequest::from_abi_tokens(&tokens[..2]),
+            Err(Eip7002DecodeError::InvalidAbiTokens)
+        );
+    }
+
+    #[test]
+    fn diff_request_sets() {
+        let request = |source: u8, amount: u64| WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(source)),
+            validator_pubkey: PUBKEY,
+            amount: Gwei(amount),
+        };
+        let a = [request(1, 0), request(2, 354), request(3, 1), request(3, 1)];
+        let b = [request(3, 1), request(4, 0), request(1, 0), request(2, 355)];
+
+        let result = diff(&a, &b);
+        assert_eq!(result.in_both, [request(1, 0), request(3, 1)]);
+        assert_eq!(result.only_in_a, [request(2, 354), request(3, 1)]);
+        assert_eq!(result.only_in_b, [request(4, 0), request(2, 355)]);
+        assert!(!result.is_empty());
+
+        // the order of the requests does not matter
+        let mut shuffled = a;
+        shuffled.reverse();
+        let result = diff(&a, &shuffled);
+        assert!(result.is_empty());
+        assert_eq!(result.in_both, a);
+
+        // disjoint sets
+        let result = diff(&a[..2], &b[1..2]);
+        assert!(result.in_both.is_empty());
+        assert_eq!(result.only_in_a, a[..2]);
+        assert_eq!(result.only_in_b, b[1..2]);
+
+        assert_eq!(diff(&[], &[]), RequestDiff::default());
+    }
+
+    #[test]
+    fn sort_dedup_requests() {
+        let request = WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(2)),
+            validator_pubkey: PUBKEY,
+            amount: Gwei(354),
+        };
+        // near duplicates, differing from `request` in a single field
+        let other_amount = WithdrawalRequest { amount: Gwei(355), ..request };
+        let other_pubkey = WithdrawalRequest { validator_pubkey: FixedBytes::ZERO, ..request };
+        let other_source = WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(1)),
+            ..request
+        };
+
+        let requests =
+            vec![request, other_amount, request, other_source, other_pubkey, request, other_amount];
+        let expected = [other_source, other_pubkey, request, other_amount];
+        assert_eq!(sort_dedup(requests.clone()), expected);
+
+        // the result does not depend on the input order
+        let mut reversed = requests;
+        reversed.reverse();
+        assert_eq!(sort_dedup(reversed), expected);
+
+        assert!(sort_dedup(vec![]).is_empty());
+    }
+
+    #[test]
+    fn columns_roundtrip() {
+        let requests: Vec<_> = (0..3u8)
+            .map(|i| WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(i)),
+                validator_pubkey: FixedBytes::repeat_byte(i),
+                amount: Gwei(354 * i as u64),
+            })
+            .collect();
+
+        let (source_addresses, validator_pubkeys, amounts) = to_columns(&requests);
+        assert_eq!(source_addresses, (0..3).map(Address::with_last_byte).collect::<Vec<_>>());
+        assert_eq!(validator_pubkeys[2], FixedBytes::repeat_byte(2));
+        assert_eq!(amounts, [0, 354, 708]);
+        assert_eq!(from_columns(&source_addresses, &validator_pubkeys, &amounts), Ok(requests));
+
+        assert_eq!(
+            from_columns(&source_addresses, &validator_pubkeys[..2], &amounts),
+            Err(Eip7002DecodeError::UnexpectedLength { expected: 3, got: 2 })
+        );
+        assert_eq!(
+            from_columns(&source_addresses[..1], &validator_pubkeys[..1], &amounts),
+            Err(Eip7002DecodeError::UnexpectedLength { expected: 1, got: 3 })
+        );
+        assert_eq!(from_columns(&[], &[], &[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn coalesce_requests() {
+        let request = |source: u8, amount: u64| WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(source)),
+            validator_pubkey: PUBKEY,
+            amount: Gwei(amount),
+        };
+
+        // two partials and a full exit for the same validator
+        let requests = [request(1, 100), request(2, 7), request(1, 254), request(1, 0)];
+        assert_eq!(coalesce(&requests), [request(1, 0), request(2, 7)]);
+
+        // a full exit absorbs the partials that follow it
+        assert_eq!(coalesce(&[request(1, 0), request(1, 354)]), [request(1, 0)]);
+
+        // partials are summed, saturating at the maximum amount
+        assert_eq!(coalesce(&[request(1, 100), request(1, 254)]), [request(1, 354)]);
+        assert_eq!(coalesce(&[request(1, u64::MAX - 1), request(1, 2)]), [request(1, u64::MAX)]);
+
+        assert!(coalesce(&[]).is_empty());
+    }
+
+    #[test]
+    fn request_stats() {
+        let alice = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let bob = address!("00000000219ab540356cbb839cbe05303d7705fa");
+        let requests = [
+            WithdrawalRequest {
+                source_address: SourceAddress(alice),
+                validator_pubkey: PUBKEY,
+                amount: Gwei::ZERO,
+            },
+            WithdrawalRequest {
+                source_address: SourceAddress(alice),
+                validator_pubkey: PUBKEY,
+                amount: Gwei::MAX,
+            },
+            WithdrawalRequest {
+                source_address: SourceAddress(bob),
+                validator_pubkey: PUBKEY,
+                amount: Gwei(354),
+            },
+            WithdrawalRequest {
+                source_address: SourceAddress(bob),
+                validator_pubkey: PUBKEY,
+                amount: Gwei::ZERO,
+            },
+        ];
+        assert_eq!(
+            RequestStats::from_requests(&requests),
+            RequestStats {
+                count: 4,
+                full_exits: 2,
+                total_amount: u64::MAX as u128 + 354,
+                distinct_sources: 2,
+            }
+        );
+        assert_eq!(RequestStats::from_requests(&[]), RequestStats::default());
+    }
+
+    #[test]
+    fn requested_totals() {
+        let request =
+            |amount: u64| WithdrawalRequest { amount: Gwei(amount), ..Default::default() };
+        let requests = [request(354), request(0), request(u64::MAX), request(0), request(1)];
+        assert_eq!(total_requested_gwei(&requests), u64::MAX as u128 + 355);
+        assert_eq!(full_exit_count(&requests), 2);
+
+        // full exits do not contribute to the total
+        assert_eq!(total_requested_gwei(&requests[1..2]), 0);
+        assert_eq!(full_exit_count(&requests[1..2]), 1);
+
+        assert_eq!(total_requested_gwei(&[]), 0);
+        assert_eq!(full_exit_count(&[]), 0);
+    }
+
+    #[test]
+    fn encode_into_preallocated_buffer() {
+        let requests: Vec<WithdrawalRequest> = [0, 1, 127, 128, 354, u64::MAX]
+            .into_iter()
+            .map(|amount| (Address::with_last_byte(amount as u8), PUBKEY, amount).into())
+            .collect();
+        for request in &requests {
+            assert_eq!(request.length(), alloy_rlp::encode(request).len());
+        }
+
+        let total = requests.iter().map(Encodable::length).sum();
+        let mut out = BytesMut::with_capacity(total);
+        let ptr = out.as_ptr();
+        for request in &requests {
+            request.encode_into(&mut out);
+        }
+        assert_eq!(out.len(), total);
+        assert_eq!(out.as_ptr(), ptr);
+
+        let mut buf = &out[..];
+        for request in &requests {
+            assert_eq!(&WithdrawalRequest::decode(&mut buf).unwrap(), request);
+        }
+    }
+
+    #[test]
+    fn encode_to_array() {
+        assert_eq!(WithdrawalRequest::MAX_ENCODED_LEN, 81);
+        for amount in [0, 1, 354, 1 << 56, u64::MAX] {
+            let request = WithdrawalRequest::from((Address::with_last_byte(1), PUBKEY, amount));
+            let (buf, len) = request.encode_to_array();
+            assert!(len <= WithdrawalRequest::MAX_ENCODED_LEN);
+            assert_eq!(len, request.length());
+            assert_eq!(alloy_rlp::decode_exact::<WithdrawalRequest>(&buf[..len]), Ok(request));
+        }
+        let max = WithdrawalRequest { amount: Gwei::MAX, ..Default::default() };
+        assert_eq!(max.length(), WithdrawalRequest::MAX_ENCODED_LEN);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_from_reader_consumes_one_request() {
+        let requests = [
+            WithdrawalRequest::default(),
+            sample_request(),
+            WithdrawalRequest { amount: Gwei::MAX, ..Default::default() },
+        ];
+        let mut encoded = Vec::new();
+        for request in &requests {
+            request.encode(&mut encoded);
+        }
+
+        let mut reader = std::io::Cursor::new(&encoded);
+        for request in requests {
+            let position = reader.position() as usize;
+            assert_eq!(WithdrawalRequest::decode_from_reader(&mut reader).unwrap(), request);
+            assert_eq!(reader.position() as usize - position, request.length());
+        }
+        assert_eq!(reader.position() as usize, encoded.len());
+
+        let err = WithdrawalRequest::decode_from_reader(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let err = WithdrawalRequest::decode_from_reader(&mut &encoded[..10]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let err = WithdrawalRequest::decode_from_reader(&mut &[0x80][..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn decode_lenient_leading_zeros() {
+        let request = sample_request();
+        let canonical = alloy_rlp::encode(request);
+        assert_eq!(WithdrawalRequest::decode_lenient(&mut canonical.as_slice()), Ok(request));
+
+        // the amount `0x820162` padded to `0x83000162`
+        let mut padded = canonical.clone();
+        padded[1] += 1;
+        padded.splice(canonical.len() - 3.., [0x83, 0x00, 0x01, 0x62]);
+        assert_eq!(
+            WithdrawalRequest::decode(&mut padded.as_slice()),
+            Err(alloy_rlp::Error::LeadingZero)
+        );
+        let mut buf = padded.as_slice();
+        assert_eq!(WithdrawalRequest::decode_lenient(&mut buf), Ok(request));
+        assert!(buf.is_empty());
+
+        // amounts below `0x80` encoded as one-byte strings, including zero
+        for amount in [0x00, 0x05] {
+            let request = WithdrawalRequest { amount: Gwei(amount), ..request };
+            let canonical = alloy_rlp::encode(request);
+            let mut string = canonical.clone();
+            string[1] += 1;
+            string.splice(canonical.len() - 1.., [0x81, amount as u8]);
+            assert_eq!(
+                WithdrawalRequest::decode(&mut string.as_slice()),
+                Err(alloy_rlp::Error::NonCanonicalSingleByte)
+            );
+            let mut buf = string.as_slice();
+            assert_eq!(WithdrawalRequest::decode_lenient(&mut buf), Ok(request));
+            assert!(buf.is_empty());
+        }
+
+        // trailing list items are still rejected
+        let mut extended = canonical;
+        extended[1] += 1;
+        extended.push(0x80);
+        assert!(WithdrawalRequest::decode_lenient(&mut extended.as_slice()).is_err());
+    }
+
+    #[test]
+    fn request_roundtrip() {
+        let request = sample_request();
+        let mut encoded = Vec::new();
+        request.encode_request(&mut encoded);
+        assert_eq!(encoded[0], WITHDRAWAL_REQUEST_TYPE);
+        assert_eq!(encoded[1..], alloy_rlp::encode(request));
+
+        let mut buf = encoded.as_slice();
+        assert_eq!(WithdrawalRequest::decode_request(&mut buf).unwrap(), request);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn decode_request_trailing_bytes() {
+        let request = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let mut encoded = Vec::new();
+        request.encode_request(&mut encoded);
+
+        // clean input
+        assert_eq!(WithdrawalRequest::decode_request_exact(&encoded), Ok(request));
+        assert_eq!(WithdrawalRequest::decode_request_lenient(&encoded), Ok(request));
+
+        // a stray type byte is only tolerated by the lenient decoder
+        let mut stray_type = encoded.clone();
+        stray_type.push(WITHDRAWAL_REQUEST_TYPE);
+        assert_eq!(
+            WithdrawalRequest::decode_request_exact(&stray_type),
+            Err(Eip7002DecodeError::Rlp(alloy_rlp::Error::UnexpectedLength))
+        );
+        assert_eq!(WithdrawalRequest::decode_request_lenient(&stray_type), Ok(request));
+
+        // any other trailing bytes are rejected
+        for garbage in [&[0x02][..], &[WITHDRAWAL_REQUEST_TYPE; 2], &[0xc0]] {
+            let mut trailing = encoded.clone();
+            trailing.extend_from_slice(garbage);
+            assert_eq!(
+                WithdrawalRequest::decode_request_exact(&trailing),
+                Err(Eip7002DecodeError::Rlp(alloy_rlp::Error::UnexpectedLength))
+            );
+            assert_eq!(
+                WithdrawalRequest::decode_request_lenient(&trailing),
+                Err(Eip7002DecodeError::Rlp(alloy_rlp::Error::UnexpectedLength))
+            );
+        }
+    }
+
+    #[test]
+    fn decode_request_invalid_type() {
+        let mut encoded = Vec::new();
+        WithdrawalRequest::default().encode_request(&mut encoded);
+        encoded[0] = crate::eip7251::CONSOLIDATION_REQUEST_TYPE;
+        assert_eq!(
+            WithdrawalRequest::decode_request(&mut encoded.as_slice()),
+            Err(Eip7002DecodeError::BadTypePrefix(crate::eip7251::CONSOLIDATION_REQUEST_TYPE))
+        );
+        assert_eq!(
+            WithdrawalRequest::decode_request(&mut &[][..]),
+            Err(Eip7002DecodeError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn withdrawal_kind() {
+        let request = WithdrawalRequest { validator_pubkey: PUBKEY, ..Default::default() };
+        assert!(request.is_full_exit());
+        assert_eq!(request.kind(), WithdrawalKind::FullExit);
+
+        for amount in [1, 354, u64::MAX] {
+            let request = WithdrawalRequest { amount: Gwei(amount), ..request };
+            assert!(!request.is_full_exit());
+            assert_eq!(request.kind(), WithdrawalKind::Partial(Gwei(amount)));
+        }
+    }
+
+    #[test]
+    fn priority_orderings() {
+        let requests: Vec<WithdrawalRequest> = [354, 0, 1, 0, u64::MAX, 354]
+            .into_iter()
+            .enumerate()
+            .map(|(i, amount)| WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(i as u8)),
+                amount: Gwei(amount),
+                ..Default::default()
+            })
+            .collect();
+        let sources = |requests: &[WithdrawalRequest]| {
+            requests.iter().map(|request| request.source_address.0[19]).collect::<Vec<_>>()
+        };
+
+        let mut sorted = requests.clone();
+        sorted.sort_by(by_amount_desc);
+        assert_eq!(sources(&sorted), [4, 0, 5, 2, 1, 3]);
+
+        let mut sorted = requests.clone();
+        sorted.sort_by(by_full_exit_first);
+        assert_eq!(sources(&sorted), [1, 3, 0, 2, 4, 5]);
+
+        let mut sorted = requests;
+        sorted.sort_by(|a, b| by_full_exit_first(a, b).then_with(|| by_amount_desc(a, b)));
+        assert_eq!(sources(&sorted), [1, 3, 4, 0, 5, 2]);
+    }
+
+    #[test]
+    fn full_exits_and_partial_withdrawals() {
+        let requests: Vec<WithdrawalRequest> = [0, 354, 0, 1, u64::MAX]
+            .into_iter()
+            .map(|amount| WithdrawalRequest {
+                validator_pubkey: PUBKEY,
+                amount: Gwei(amount),
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(full_exits(&requests).collect::<Vec<_>>(), [&requests[0], &requests[2]]);
+        assert_eq!(
+            partial_withdrawals(&requests).collect::<Vec<_>>(),
+            [&requests[1], &requests[3], &requests[4]]
+        );
+        assert_eq!(full_exits(&[]).count(), 0);
+    }
+
+    #[test]
+    fn filter_by_amount_range() {
+        let requests: Vec<WithdrawalRequest> = [0, 99, 100, 354, 1000, 1001, u64::MAX]
+            .into_iter()
+            .map(|amount| WithdrawalRequest { amount: Gwei(amount), ..Default::default() })
+            .collect();
+        let amounts = |range| {
+            filter_amount_range(&requests, range)
+                .map(|request| request.amount.get())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(amounts(Gwei(100)..=Gwei(1000)), [100, 354, 1000]);
+        assert_eq!(amounts(Gwei(354)..=Gwei(354)), [354]);
+        assert_eq!(amounts(Gwei::ZERO..=Gwei::MAX).len(), requests.len());
+        assert!(amounts(Gwei(1000)..=Gwei(100)).is_empty());
+        assert!(amounts(Gwei(355)..=Gwei(999)).is_empty());
+    }
+
+    #[test]
+    fn group_requests_by_source() {
+        let alice = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let bob = address!("00000000219ab540356cbb839cbe05303d7705fa");
+        let requests: Vec<WithdrawalRequest> = vec![
+            (alice, PUBKEY, 1).into(),
+            (bob, PUBKEY, 2).into(),
+            (alice, PUBKEY, 3).into(),
+            (bob, FixedBytes::ZERO, 4).into(),
+            (alice, FixedBytes::ZERO, 5).into(),
+        ];
+
+        let groups = group_by_source(&requests);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&alice], [&requests[0], &requests[2], &requests[4]]);
+        assert_eq!(groups[&bob], [&requests[1], &requests[3]]);
+        assert!(group_by_source(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resolve_validator_indices() {
+        let other = fixed_bytes!("a9e9d91bb1b6c1df9b6d5e5e9ff3a3b3e2b8f50e9d7d65ff24b98d47a2c1f0e47c7b1d0d2e1a5f7b3c6a2e8f9d0c1b2a");
+        let registry = std::collections::HashMap::from([(PUBKEY, 42), (other, 7)]);
+        let requests: Vec<WithdrawalRequest> = vec![
+            (Address::ZERO, PUBKEY, 0).into(),
+            (Address::ZERO, FixedBytes::ZERO, 0).into(),
+            (Address::ZERO, other, 0).into(),
+        ];
+        assert_eq!(resolve_indices(&requests, &registry), [Some(42), None, Some(7)]);
+    }
+
+    #[test]
+    fn compute_excess_transitions() {
+        // below the target, the excess decays to zero
+        assert_eq!(compute_excess(0, 0), 0);
+        assert_eq!(compute_excess(0, 1), 0);
+        assert_eq!(compute_excess(0, TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK), 0);
+        assert_eq!(compute_excess(1, 0), 0);
+        // above the target, the excess grows by the surplus
+        assert_eq!(compute_excess(0, 3), 1);
+        assert_eq!(compute_excess(0, MAX_WITHDRAWAL_REQUESTS_PER_BLOCK), 14);
+        assert_eq!(compute_excess(14, MAX_WITHDRAWAL_REQUESTS_PER_BLOCK), 28);
+        assert_eq!(compute_excess(28, 0), 26);
+        assert_eq!(compute_excess(5, TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK), 5);
+        assert_eq!(compute_excess(u64::MAX, 1), u64::MAX - TARGET_WITHDRAWAL_REQUESTS_PER_BLOCK);
+
+        // the fee returns to the minimum once the excess has decayed
+        let mut excess = compute_excess(0, MAX_WITHDRAWAL_REQUESTS_PER_BLOCK);
+        assert!(fee(excess) > MIN_WITHDRAWAL_REQUEST_FEE);
+        for _ in 0..7 {
+            excess = compute_excess(excess, 0);
+        }
+        assert_eq!(excess, 0);
+        assert_eq!(fee(excess), MIN_WITHDRAWAL_REQUEST_FEE);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn dedup_by_validator_key() {
+        let request = WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(1)),
+            validator_pubkey: PUBKEY,
+            amount: Gwei(354),
+        };
+        let top_up = WithdrawalRequest { amount: Gwei(1), ..request };
+        let other = WithdrawalRequest {
+            source_address: SourceAddress(Address::with_last_byte(2)),
+            ..request
+        };
+        assert_eq!(request.validator_key(), (request.source_address.0, PUBKEY));
+        assert_eq!(request.validator_key(), top_up.validator_key());
+
+        let mut seen = std::collections::HashSet::new();
+        assert!(seen.insert(request.validator_key()));
+        assert!(!seen.insert(top_up.validator_key()));
+        assert!(seen.insert(other.validator_key()));
+        assert_eq!(seen.len(), 2);
+    }
+
+    #[test]
+    fn validator_pubkey_newtype() {
+        let request = WithdrawalRequest { validator_pubkey: PUBKEY, ..Default::default() };
+        let pubkey = request.typed_validator_pubkey();
+        assert_eq!(pubkey, ValidatorPubkey::from(PUBKEY));
+        assert_eq!(FixedBytes::from(pubkey), PUBKEY);
+        assert_eq!(alloy_rlp::encode(pubkey), alloy_rlp::encode(PUBKEY));
+        assert_eq!(
+            alloy_rlp::decode_exact::<ValidatorPubkey>(alloy_rlp::encode(PUBKEY)),
+            Ok(pubkey)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_validator_pubkey_newtype() {
+        let pubkey = ValidatorPubkey(PUBKEY);
+        let json = serde_json::to_string(&pubkey).unwrap();
+        assert_eq!(json, serde_json::to_string(&PUBKEY).unwrap());
+        assert_eq!(serde_json::from_str::<ValidatorPubkey>(&json).unwrap(), pubkey);
+    }
+
+    #[test]
+    fn source_address_newtype() {
+        let address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let source_address = SourceAddress::from(address);
+        assert_eq!(Address::from(source_address), address);
+        assert_eq!(source_address.to_string(), address.to_string());
+        assert_eq!(alloy_rlp::encode(source_address), alloy_rlp::encode(address));
+        assert_eq!(
+            alloy_rlp::decode_exact::<SourceAddress>(alloy_rlp::encode(address)),
+            Ok(source_address)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_source_address_newtype() {
+        let address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let json = serde_json::to_string(&SourceAddress(address)).unwrap();
+        assert_eq!(json, serde_json::to_string(&address).unwrap());
+        assert_eq!(serde_json::from_str::<SourceAddress>(&json).unwrap(), SourceAddress(address));
+
+        // the request serializes its source address as a plain address
+        let request =
+            WithdrawalRequest { source_address: SourceAddress(address), ..Default::default() };
+        let value = serde_json::to_value(request).unwrap();
+        assert_eq!(value["sourceAddress"], serde_json::to_value(address).unwrap());
+    }
+
+    #[test]
+    fn withdrawal_requests_rlp_list() {
+        use alloy_rlp::{Decodable, Encodable};
+
+        let request = sample_request();
+
+        for requests in
+            [vec![], vec![request], vec![request, WithdrawalRequest::default(), request]]
+        {
+            let requests = WithdrawalRequests::from(requests);
+            let encoded = alloy_rlp::encode(&requests);
+            assert_eq!(encoded.len(), requests.length());
+
+            // encoded as a list of the individual requests
+            let mut payload = encoded.as_slice();
+            let header = alloy_rlp::Header::decode(&mut payload).unwrap();
+            assert!(header.list);
+            assert_eq!(
+                payload,
+                requests.iter().flat_map(alloy_rlp::encode).collect::<Vec<_>>().as_slice()
+            );
+
+            let decoded: WithdrawalRequests = alloy_rlp::decode_exact(&encoded).unwrap();
+            assert_eq!(decoded, requests);
+            assert_eq!(decoded.len(), requests.len());
+
+            let mut trailing = encoded.clone();
+            trailing.push(0x80);
+            assert_eq!(
+                alloy_rlp::decode_exact::<WithdrawalRequests>(&trailing),
+                Err(alloy_rlp::Error::UnexpectedLength)
+            );
+            assert_eq!(WithdrawalRequests::decode(&mut trailing.as_slice()).unwrap(), requests);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh_roundtrip() {
+        let request = sample_request();
+        let encoded = borsh::to_vec(&request).unwrap();
+        assert_eq!(encoded.len(), 20 + 48 + 8);
+        assert_eq!(encoded[..20], request.source_address.0[..]);
+        assert_eq!(encoded[20..68], PUBKEY[..]);
+        assert_eq!(encoded[68..], 354u64.to_le_bytes());
+        assert_eq!(borsh::from_slice::<WithdrawalRequest>(&encoded).unwrap(), request);
+        assert!(borsh::from_slice::<WithdrawalRequest>(&encoded[..75]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize_withdrawal_request() {
+        use zeroize::Zeroize;
+
+        let mut request = sample_request();
+        request.zeroize();
+        assert!(request.validator_pubkey.is_zero());
+        assert_eq!(request, WithdrawalRequest::default());
+    }
+}
@@ -0,0 +1,70 @@
+//! Conversion of [`WithdrawalRequest`]s from and to newline-delimited JSON, e.g. for log shipping.
+//!
+//! Each line holds the JSON representation of one request.
+
+use crate::eip7002::WithdrawalRequest;
+use std::io::{self, BufRead, Write};
+
+/// Writes the requests as newline-delimited JSON, one request per line.
+///
+/// Every line, including the last one, is terminated by a newline.
+pub fn to_ndjson<W: Write>(requests: &[WithdrawalRequest], w: &mut W) -> io::Result<()> {
+    for request in requests {
+        serde_json::to_writer(&mut *w, request)?;
+        w.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Reads requests from newline-delimited JSON, as written by [`to_ndjson`].
+///
+/// Blank lines are skipped. A line that is not a valid request is reported as an
+/// [`io::ErrorKind::InvalidData`] error.
+pub fn from_ndjson<R: BufRead>(r: R) -> io::Result<Vec<WithdrawalRequest>> {
+    let mut requests = Vec::new();
+    for line in r.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        requests.push(serde_json::from_str(&line)?);
+    }
+    Ok(requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eip7002::SourceAddress, Gwei};
+    use alloy_primitives::{Address, FixedBytes};
+
+    #[test]
+    fn ndjson_roundtrip() {
+        let requests: Vec<_> = (0..3u8)
+            .map(|i| WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(i)),
+                validator_pubkey: FixedBytes::repeat_byte(i),
+                amount: Gwei(354 * i as u64),
+            })
+            .collect();
+        assert!(requests[0].is_full_exit());
+
+        let mut out = Vec::new();
+        to_ndjson(&requests, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), requests.len());
+        assert!(text.ends_with('\n'));
+        for (line, request) in text.lines().zip(&requests) {
+            assert_eq!(line, serde_json::to_string(request).unwrap());
+        }
+        assert_eq!(from_ndjson(text.as_bytes()).unwrap(), requests);
+
+        // blank lines are skipped
+        let spaced = text.replace('\n', "\n\n");
+        assert_eq!(from_ndjson(spaced.as_bytes()).unwrap(), requests);
+
+        assert!(from_ndjson(&b""[..]).unwrap().is_empty());
+        let err = from_ndjson(&b"{}\n"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,86 @@
+//! Engine API representation of [`WithdrawalRequest`].
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::{Address, FixedBytes};
+
+/// A withdrawal request as it appears in the Engine API, matching the `WithdrawalRequestV1`
+/// object of the [execution-apis](https://github.com/ethereum/execution-apis/blob/main/src/engine/prague.md)
+/// specification.
+///
+/// Unlike [`WithdrawalRequest`], which is the canonical representation, this type only
+/// implements the serialization used over JSON-RPC and rejects unknown fields.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RpcWithdrawalRequest {
+    /// Address of the source of the exit.
+    pub source_address: Address,
+    /// Validator public key.
+    pub validator_pubkey: FixedBytes<48>,
+    /// Amount of withdrawn ether in gwei, as a hex quantity.
+    #[serde(with = "alloy_serde::quantity")]
+    pub amount: u64,
+}
+
+impl From<WithdrawalRequest> for RpcWithdrawalRequest {
+    fn from(request: WithdrawalRequest) -> Self {
+        Self {
+            source_address: request.source_address.0,
+            validator_pubkey: request.validator_pubkey,
+            amount: request.amount.0,
+        }
+    }
+}
+
+impl From<RpcWithdrawalRequest> for WithdrawalRequest {
+    fn from(request: RpcWithdrawalRequest) -> Self {
+        Self {
+            source_address: SourceAddress(request.source_address),
+            validator_pubkey: request.validator_pubkey,
+            amount: Gwei(request.amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip7002::sample_request;
+    use alloy_primitives::{address, fixed_bytes};
+
+    #[test]
+    fn deserialize_execution_apis_withdrawal_request() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#;
+
+        let request: RpcWithdrawalRequest = serde_json::from_str(input).unwrap();
+        assert_eq!(request.source_address, address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"));
+        assert_eq!(request.validator_pubkey, fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"));
+        assert_eq!(request.amount, 354);
+        assert_eq!(serde_json::to_string(&request).unwrap(), input);
+
+        let canonical = WithdrawalRequest::from(request);
+        assert_eq!(canonical, sample_request());
+        assert_eq!(RpcWithdrawalRequest::from(canonical), request);
+    }
+
+    #[test]
+    fn canonical_json_matches_rpc() {
+        for amount in [0, 354, u64::MAX] {
+            let request = WithdrawalRequest { amount: Gwei(amount), ..Default::default() };
+            assert_eq!(
+                serde_json::to_string(&request).unwrap(),
+                serde_json::to_string(&RpcWithdrawalRequest::from(request)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn reject_unknown_fields() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162","index":"0x0"}"#;
+        assert!(serde_json::from_str::<RpcWithdrawalRequest>(input).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Serde helpers for [`WithdrawalRequest`]s and lists of them.
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::vec::Vec;
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::{Bytes, FixedBytes};
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// Deserializes a list of [`WithdrawalRequest`]s, treating `null` as an empty list.
+///
+/// Some Engine API implementations send `null` rather than `[]` for an empty list of requests. Use
+/// together with `#[serde(default)]` to also accept a missing field:
+///
+/// ```
+/// use alloy_eips::eip7002::WithdrawalRequest;
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Payload {
+///     #[serde(default, deserialize_with = "alloy_eips::eip7002::serde_helpers::null_as_empty")]
+///     withdrawal_requests: Vec<WithdrawalRequest>,
+/// }
+///
+/// let payload: Payload = serde_json::from_str(r#"{"withdrawalRequests":null}"#).unwrap();
+/// assert!(payload.withdrawal_requests.is_empty());
+/// ```
+pub fn null_as_empty<'de, D>(deserializer: D) -> Result<Vec<WithdrawalRequest>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    alloy_serde::null_as_default(deserializer)
+}
+
+/// Deserializes the validator public key of a [`WithdrawalRequest`], with an error message that
+/// names the field and the actual length.
+pub(crate) fn validator_pubkey<'de, D>(deserializer: D) -> Result<FixedBytes<48>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = Bytes::deserialize(deserializer)?;
+    FixedBytes::try_from(bytes.as_ref()).map_err(|_| {
+        D::Error::custom(format_args!("validatorPubkey must be 48 bytes, got {}", bytes.len()))
+    })
+}
+
+/// A [`WithdrawalRequest`] that rejects unknown fields when deserializing.
+///
+/// [`WithdrawalRequest`] ignores unknown fields, so that fields added to the Engine API over time
+/// do not break deserialization. Security-sensitive importers can deserialize this wrapper instead
+/// to reject any unexpected key. The accepted fields and the serialized form are otherwise
+/// identical.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(transparent)]
+pub struct StrictWithdrawalRequest(pub WithdrawalRequest);
+
+impl<'de> Deserialize<'de> for StrictWithdrawalRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // mirrors the fields of `WithdrawalRequest`
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase", deny_unknown_fields)]
+        struct Strict {
+            #[serde(alias = "source_address")]
+            source_address: SourceAddress,
+            #[serde(alias = "validator_pubkey", deserialize_with = "validator_pubkey")]
+            validator_pubkey: FixedBytes<48>,
+            amount: Gwei,
+        }
+
+        let Strict { source_address, validator_pubkey, amount } =
+            Strict::deserialize(deserializer)?;
+        Ok(Self(WithdrawalRequest { source_address, validator_pubkey, amount }))
+    }
+}
+
+impl From<WithdrawalRequest> for StrictWithdrawalRequest {
+    fn from(request: WithdrawalRequest) -> Self {
+        Self(request)
+    }
+}
+
+impl From<StrictWithdrawalRequest> for WithdrawalRequest {
+    fn from(request: StrictWithdrawalRequest) -> Self {
+        request.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eip7002::sample_request;
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Payload {
+        #[serde(default, deserialize_with = "null_as_empty")]
+        withdrawal_requests: Vec<WithdrawalRequest>,
+    }
+
+    #[test]
+    fn null_and_empty_requests() {
+        for input in [r#"{"withdrawalRequests":null}"#, r#"{"withdrawalRequests":[]}"#, "{}"] {
+            let payload: Payload = serde_json::from_str(input).unwrap();
+            assert!(payload.withdrawal_requests.is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid_validator_pubkey_length() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280","amount":"0x162"}"#;
+        let err = serde_json::from_str::<WithdrawalRequest>(input).unwrap_err();
+        assert!(
+            err.to_string().starts_with("validatorPubkey must be 48 bytes, got 32"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn populated_requests() {
+        let input = r#"{"withdrawalRequests":[{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}]}"#;
+        let payload: Payload = serde_json::from_str(input).unwrap();
+        assert_eq!(payload.withdrawal_requests, vec![sample_request()]);
+    }
+
+    #[test]
+    fn strict_unknown_fields() {
+        let request = sample_request();
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#;
+        assert_eq!(
+            serde_json::from_str::<StrictWithdrawalRequest>(input).unwrap(),
+            StrictWithdrawalRequest(request)
+        );
+        assert_eq!(
+            serde_json::to_string(&StrictWithdrawalRequest(request)).unwrap(),
+            serde_json::to_string(&request).unwrap()
+        );
+
+        // the default representation is lenient, the strict one rejects the extra key
+        let extra = input.replace(r#""amount""#, r#""index":"0x1","amount""#);
+        assert_eq!(serde_json::from_str::<WithdrawalRequest>(&extra).unwrap(), request);
+        let err = serde_json::from_str::<StrictWithdrawalRequest>(&extra).unwrap_err();
+        assert!(err.to_string().starts_with("unknown field `index`"), "unexpected error: {err}");
+
+        // snake_case aliases are still accepted
+        let snake_case = input.replace("sourceAddress", "source_address");
+        assert_eq!(
+            serde_json::from_str::<StrictWithdrawalRequest>(&snake_case).unwrap().0,
+            request
+        );
+    }
+}
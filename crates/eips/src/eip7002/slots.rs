@@ -0,0 +1,78 @@
+//! The storage layout of the EIP-7002 withdrawal requests contract.
+//!
+//! These slots can be read with `eth_getStorageAt` at
+//! [`WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS`](super::WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS) to inspect
+//! the state of the request queue without calling the contract.
+
+use alloy_primitives::U256;
+
+/// The slot holding the current excess of withdrawal requests, from which the [`fee`](super::fee)
+/// is computed.
+///
+/// This is the same slot as
+/// [`EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT`](super::EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT).
+pub const EXCESS_SLOT: U256 = super::EXCESS_WITHDRAWAL_REQUESTS_STORAGE_SLOT;
+
+/// The slot holding the number of requests added in the current block.
+///
+/// The system call at the end of the block folds it into the excess and resets it to zero.
+pub const COUNT_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// The slot holding the queue index of the next request to dequeue.
+pub const QUEUE_HEAD_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// The slot holding the queue index at which the next request is stored.
+///
+/// The queue is empty when the head and tail indices are equal, and both are reset to zero once
+/// the queue has been drained.
+pub const QUEUE_TAIL_SLOT: U256 = U256::from_limbs([3, 0, 0, 0]);
+
+/// The first slot of the queue of pending requests.
+///
+/// Each request occupies [`QUEUE_ENTRY_SLOTS`] consecutive slots, so the request at queue index
+/// `i` starts at slot `QUEUE_STORAGE_OFFSET + i * QUEUE_ENTRY_SLOTS`.
+pub const QUEUE_STORAGE_OFFSET: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+/// The number of storage slots occupied by each request in the queue.
+pub const QUEUE_ENTRY_SLOTS: u64 = 3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eip7002::WithdrawalRequestContract, system_contract::SystemContract};
+    use alloy_primitives::hex;
+
+    fn contains(code: &[u8], needle: &[u8]) -> bool {
+        code.windows(needle.len()).any(|window| window == needle)
+    }
+
+    #[test]
+    fn slots_are_distinct() {
+        let slots = [EXCESS_SLOT, COUNT_SLOT, QUEUE_HEAD_SLOT, QUEUE_TAIL_SLOT];
+        for (i, a) in slots.iter().enumerate() {
+            assert!(slots[i + 1..].iter().all(|b| a != b));
+            assert!(*a < QUEUE_STORAGE_OFFSET);
+        }
+    }
+
+    #[test]
+    fn slots_match_bytecode() {
+        let code = WithdrawalRequestContract::CODE;
+        assert_eq!(EXCESS_SLOT, U256::ZERO);
+
+        // `sload(excess)` when computing the fee, as `PUSH0 SLOAD`
+        assert!(contains(code, &hex!("5f545f52")));
+        // `sstore(count, sload(count) + 1)` when adding a request
+        assert!(contains(code, &hex!("600154600101600155")));
+        // `sload(tail) * 3 + 4` to locate the queue entry of a new request
+        assert!(contains(code, &hex!("6003548060030260040133")));
+        // `sload(tail)` and `sload(head)` when dequeuing requests
+        assert!(contains(code, &hex!("6003546002548082038060101160a4")));
+
+        assert_eq!(COUNT_SLOT, U256::from(1));
+        assert_eq!(QUEUE_HEAD_SLOT, U256::from(2));
+        assert_eq!(QUEUE_TAIL_SLOT, U256::from(3));
+        assert_eq!(QUEUE_STORAGE_OFFSET, U256::from(4));
+        assert_eq!(QUEUE_ENTRY_SLOTS, 3);
+    }
+}
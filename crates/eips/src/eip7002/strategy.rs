@@ -0,0 +1,67 @@
+//! [`proptest`](mod@proptest) strategies for [`WithdrawalRequest`]s.
+//!
+//! Unlike the `arbitrary` implementations, which are meant for fuzzing, these generate amounts
+//! bounded by [`MAX_EFFECTIVE_BALANCE`], i.e. realistic validator balances.
+
+use crate::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    eip7251::MAX_EFFECTIVE_BALANCE,
+    Gwei,
+};
+use alloy_primitives::{Address, FixedBytes};
+use proptest::prelude::*;
+
+/// Returns a strategy for amounts between zero and [`MAX_EFFECTIVE_BALANCE`] gwei, inclusive.
+pub fn amount() -> impl Strategy<Value = Gwei> {
+    (0..=MAX_EFFECTIVE_BALANCE).prop_map(Gwei)
+}
+
+/// Returns a strategy for requests with an arbitrary source address and validator public key, and
+/// an [`amount`] up to [`MAX_EFFECTIVE_BALANCE`] gwei.
+///
+/// Full exits are generated as rarely as any other amount, see
+/// [`withdrawal_request_with_full_exit_bias`] to generate them more often.
+pub fn withdrawal_request() -> impl Strategy<Value = WithdrawalRequest> {
+    (any::<[u8; 20]>(), any::<[u8; 48]>(), amount()).prop_map(
+        |(source_address, validator_pubkey, amount)| WithdrawalRequest {
+            source_address: SourceAddress(Address::new(source_address)),
+            validator_pubkey: FixedBytes(validator_pubkey),
+            amount,
+        },
+    )
+}
+
+/// Returns a strategy like [`withdrawal_request`] that generates a full exit with the given
+/// probability, which must be between `0.0` and `1.0`.
+pub fn withdrawal_request_with_full_exit_bias(
+    probability: f64,
+) -> impl Strategy<Value = WithdrawalRequest> {
+    (withdrawal_request(), proptest::bool::weighted(probability)).prop_map(
+        |(request, full_exit)| {
+            if full_exit {
+                WithdrawalRequest { amount: Gwei::ZERO, ..request }
+            } else {
+                request
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn rlp_roundtrip(request in withdrawal_request()) {
+            prop_assert!(request.amount.get() <= MAX_EFFECTIVE_BALANCE);
+            let encoded = alloy_rlp::encode(request);
+            prop_assert_eq!(alloy_rlp::decode_exact::<WithdrawalRequest>(&encoded), Ok(request));
+        }
+
+        #[test]
+        fn full_exit_bias(request in withdrawal_request_with_full_exit_bias(1.0)) {
+            prop_assert!(request.is_full_exit());
+        }
+    }
+}
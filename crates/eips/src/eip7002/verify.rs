@@ -0,0 +1,71 @@
+//! Pluggable verification of [`WithdrawalRequest`]s.
+//!
+//! Withdrawal requests are not signed at the execution layer, but tooling may want to cross-check
+//! them against other data, e.g. the validator's consensus key. [`RequestVerifier`] is the
+//! extension point for such checks.
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::vec::Vec;
+
+use crate::eip7002::WithdrawalRequest;
+
+/// A user-provided check that is applied to individual [`WithdrawalRequest`]s.
+pub trait RequestVerifier {
+    /// Returns `true` if the request passes verification.
+    fn verify(&self, request: &WithdrawalRequest) -> bool;
+}
+
+/// A [`RequestVerifier`] that performs no checks and accepts every request.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NoopVerifier;
+
+impl RequestVerifier for NoopVerifier {
+    fn verify(&self, _request: &WithdrawalRequest) -> bool {
+        true
+    }
+}
+
+impl<V: RequestVerifier + ?Sized> RequestVerifier for &V {
+    fn verify(&self, request: &WithdrawalRequest) -> bool {
+        (**self).verify(request)
+    }
+}
+
+/// Verifies each of the given requests, returning the results in the same order.
+pub fn verify_batch<V: RequestVerifier>(requests: &[WithdrawalRequest], verifier: &V) -> Vec<bool> {
+    requests.iter().map(|request| verifier.verify(request)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eip7002::SourceAddress, Gwei};
+    use alloy_primitives::Address;
+
+    /// Approves requests from a single source address.
+    struct SourceVerifier(SourceAddress);
+
+    impl RequestVerifier for SourceVerifier {
+        fn verify(&self, request: &WithdrawalRequest) -> bool {
+            request.source_address == self.0
+        }
+    }
+
+    #[test]
+    fn verify_batch_with_stub() {
+        let approved = SourceAddress(Address::with_last_byte(1));
+        let requests = [
+            WithdrawalRequest { source_address: approved, ..Default::default() },
+            WithdrawalRequest {
+                source_address: SourceAddress(Address::with_last_byte(2)),
+                ..Default::default()
+            },
+            WithdrawalRequest { source_address: approved, amount: Gwei(1), ..Default::default() },
+        ];
+
+        assert_eq!(verify_batch(&requests, &SourceVerifier(approved)), [true, false, true]);
+        assert_eq!(verify_batch(&requests, &NoopVerifier), [true; 3]);
+        assert!(verify_batch(&[], &NoopVerifier).is_empty());
+    }
+}
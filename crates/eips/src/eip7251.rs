@@ -16,6 +16,14 @@ pub static CONSOLIDATION_REQUEST_PREDEPLOY_CODE: Bytes = bytes!("3373fffffffffff
 /// The [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) request type for consolidation requests.
 pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
 
+/// The size in bytes of a single consolidation request in the packed output of the EIP-7251
+/// consolidation requests contract: `source_address (20) || source_pubkey (48) || target_pubkey
+/// (48)`.
+pub const CONSOLIDATION_REQUEST_PACKED_SIZE: usize = 116;
+
+/// The maximum effective balance of a validator in gwei, raised to 2048 ether by EIP-7251.
+pub const MAX_EFFECTIVE_BALANCE: u64 = 2_048_000_000_000;
+
 /// This structure maps onto the consolidation request object from [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,3 +38,65 @@ pub struct ConsolidationRequest {
     /// Target public key
     pub target_pubkey: FixedBytes<48>,
 }
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ConsolidationRequest {
+    /// Zeroes all fields of the request, including its public key material.
+    ///
+    /// The request is `Copy`, so it is not zeroized on drop and this must be called explicitly.
+    fn zeroize(&mut self) {
+        self.source_address.0 .0.zeroize();
+        self.source_pubkey.0.zeroize();
+        self.target_pubkey.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::fixed_bytes;
+    use alloy_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn test_encode_decode_consolidation_request_roundtrip() {
+        let original_request = ConsolidationRequest {
+            source_address: address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"),
+            source_pubkey: fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"),
+            target_pubkey: fixed_bytes!("a9e9d91bb1b6c1df9b6d5e5e9ff3a3b3e2b8f50e9d7d65ff24b98d47a2c1f0e47c7b1d0d2e1a5f7b3c6a2e8f9d0c1b2a"),
+        };
+
+        let mut buf = Vec::new();
+        original_request.encode(&mut buf);
+        assert_eq!(buf.len(), original_request.length());
+
+        let decoded = ConsolidationRequest::decode(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, original_request);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_consolidation_request() {
+        let input = r#"{"sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","sourcePubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","targetPubkey":"0xa9e9d91bb1b6c1df9b6d5e5e9ff3a3b3e2b8f50e9d7d65ff24b98d47a2c1f0e47c7b1d0d2e1a5f7b3c6a2e8f9d0c1b2a"}"#;
+
+        let request: ConsolidationRequest = serde_json::from_str(input).unwrap();
+        assert_eq!(request.source_address, address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"));
+        assert_eq!(request.source_pubkey, fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"));
+
+        let s = serde_json::to_string(&request).unwrap();
+        assert_eq!(s, input);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_zeroize_consolidation_request() {
+        use zeroize::Zeroize;
+
+        let mut request = ConsolidationRequest {
+            source_address: address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E"),
+            source_pubkey: fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b"),
+            target_pubkey: fixed_bytes!("a9e9d91bb1b6c1df9b6d5e5e9ff3a3b3e2b8f50e9d7d65ff24b98d47a2c1f0e47c7b1d0d2e1a5f7b3c6a2e8f9d0c1b2a"),
+        };
+        request.zeroize();
+        assert_eq!(request, ConsolidationRequest::default());
+    }
+}
@@ -0,0 +1,151 @@
+//! A length-prefixed framing codec for [`PrefixedRequestBytes`].
+
+use crate::eip7685::PrefixedRequestBytes;
+use alloy_rlp::BytesMut;
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec};
+
+/// A [`tokio_util::codec`] codec that frames each [`PrefixedRequestBytes`] with its length, as a
+/// 4-byte big-endian integer.
+///
+/// Frames longer than the configured maximum, [`FrameCodec::DEFAULT_MAX_FRAME_LEN`] by default,
+/// are rejected with an [`std::io::Error`], both when encoding and when decoding. Partial frames
+/// are buffered until the remaining bytes arrive.
+#[derive(Debug)]
+pub struct FrameCodec {
+    inner: LengthDelimitedCodec,
+}
+
+impl FrameCodec {
+    /// The default maximum length of a frame: 1 MiB.
+    pub const DEFAULT_MAX_FRAME_LEN: usize = 1 << 20;
+
+    /// Creates a new codec with the [default](Self::DEFAULT_MAX_FRAME_LEN) maximum frame length.
+    pub fn new() -> Self {
+        Self::with_max_frame_len(Self::DEFAULT_MAX_FRAME_LEN)
+    }
+
+    /// Creates a new codec that rejects frames longer than `max_frame_len` bytes, excluding the
+    /// length prefix.
+    pub fn with_max_frame_len(max_frame_len: usize) -> Self {
+        let inner = LengthDelimitedCodec::builder()
+            .length_field_length(4)
+            .big_endian()
+            .max_frame_length(max_frame_len)
+            .new_codec();
+        Self { inner }
+    }
+
+    /// Returns the maximum frame length, excluding the length prefix.
+    pub fn max_frame_len(&self) -> usize {
+        self.inner.max_frame_length()
+    }
+}
+
+impl Default for FrameCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for FrameCodec {
+    type Item = PrefixedRequestBytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.inner.decode(src)?.map(|frame| PrefixedRequestBytes(frame.freeze().into())))
+    }
+}
+
+impl Encoder<PrefixedRequestBytes> for FrameCodec {
+    type Error = std::io::Error;
+
+    fn encode(
+        &mut self,
+        item: PrefixedRequestBytes,
+        dst: &mut BytesMut,
+    ) -> Result<(), Self::Error> {
+        self.inner.encode(item.0 .0, dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eip7002::WithdrawalRequest,
+        eip7685::{Encodable7685, Request},
+        Gwei,
+    };
+    use alloy_primitives::Bytes;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_util::codec::{FramedRead, FramedWrite};
+
+    #[tokio::test]
+    async fn framed_roundtrip() {
+        let frames: Vec<PrefixedRequestBytes> = [
+            Request::WithdrawalRequest(WithdrawalRequest::default()),
+            Request::WithdrawalRequest(WithdrawalRequest {
+                amount: Gwei(354),
+                ..Default::default()
+            }),
+            Request::ConsolidationRequest(Default::default()),
+        ]
+        .into_iter()
+        .map(Into::into)
+        .chain([PrefixedRequestBytes::default()])
+        .collect();
+
+        // a small buffer forces partial reads of the frames
+        let (writer, reader) = tokio::io::duplex(7);
+        let mut sink = FramedWrite::new(writer, FrameCodec::new());
+        let mut stream = FramedRead::new(reader, FrameCodec::new());
+
+        let sent = frames.clone();
+        let send = tokio::spawn(async move {
+            for frame in sent {
+                sink.send(frame).await.unwrap();
+            }
+        });
+        for frame in &frames {
+            assert_eq!(stream.next().await.unwrap().unwrap(), *frame);
+        }
+        send.await.unwrap();
+        assert!(stream.next().await.is_none());
+
+        let request = frames[1].try_decode().unwrap();
+        assert_eq!(request.encoded_7685(), frames[1].as_bytes()[..]);
+    }
+
+    #[test]
+    fn decode_partial_frame() {
+        let mut codec = FrameCodec::new();
+        let mut buf = BytesMut::new();
+        codec.encode(PrefixedRequestBytes(Bytes::from_static(&[1, 2, 3])), &mut buf).unwrap();
+        assert_eq!(buf[..], [0, 0, 0, 3, 1, 2, 3]);
+
+        let mut partial = buf.split_to(5);
+        assert_eq!(codec.decode(&mut partial).unwrap(), None);
+        partial.unsplit(buf);
+        assert_eq!(
+            codec.decode(&mut partial).unwrap(),
+            Some(PrefixedRequestBytes(Bytes::from_static(&[1, 2, 3])))
+        );
+        assert!(partial.is_empty());
+    }
+
+    #[test]
+    fn reject_oversized_frames() {
+        let mut codec = FrameCodec::with_max_frame_len(2);
+        assert_eq!(codec.max_frame_len(), 2);
+
+        let mut buf = BytesMut::new();
+        let err = codec
+            .encode(PrefixedRequestBytes(Bytes::from_static(&[1, 2, 3])), &mut buf)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+        let mut buf = BytesMut::from(&[0, 0, 0, 3, 1, 2, 3][..]);
+        let err = codec.decode(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
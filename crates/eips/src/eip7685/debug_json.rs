@@ -0,0 +1,181 @@
+//! Tolerant parsing of execution requests from the JSON output of client debug endpoints.
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::{string::String, vec::Vec};
+
+use crate::{
+    eip6110::DepositRequest,
+    eip7002::WithdrawalRequest,
+    eip7251::ConsolidationRequest,
+    eip7685::{parse_execution_requests, ExecutionRequestsError, Request},
+};
+use core::fmt;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+// The keys under which the object form lists the requests of each type.
+const DEPOSIT_KEYS: [&str; 2] = ["deposits", "depositRequests"];
+const WITHDRAWAL_KEYS: [&str; 2] = ["withdrawals", "withdrawalRequests"];
+const CONSOLIDATION_KEYS: [&str; 2] = ["consolidations", "consolidationRequests"];
+
+/// Parses execution requests from the JSON output of a debug endpoint, such as geth's `debug_`
+/// namespace.
+///
+/// Two shapes are accepted:
+/// - an array of `0x`-prefixed hex strings, as in the `executionRequests` of the Engine API: each
+///   entry is a request type followed by the concatenated packed requests of that type, and the
+///   entries are in strictly ascending order of their type; see [`parse_execution_requests`].
+/// - an object listing the JSON representation of the requests of each type, under the keys
+///   `deposits`, `withdrawals` and `consolidations`, or `depositRequests`, `withdrawalRequests` and
+///   `consolidationRequests`. Missing keys are treated as no requests of that type, and other keys
+///   are ignored.
+///
+/// Requests of the object form are returned ordered by request type.
+pub fn from_debug_json(value: &Value) -> Result<Vec<Request>, DebugJsonError> {
+    match value {
+        Value::Array(list) => {
+            let list = list
+                .iter()
+                .map(|request| request.as_str().ok_or(DebugJsonError::UnexpectedShape))
+                .collect::<Result<Vec<_>, _>>()?;
+            parse_execution_requests(&list).map_err(DebugJsonError::ExecutionRequests)
+        }
+        Value::Object(object) => {
+            let mut requests = Vec::new();
+            requests.extend(
+                typed::<DepositRequest>(object, DEPOSIT_KEYS)?.map(Request::DepositRequest),
+            );
+            requests.extend(
+                typed::<WithdrawalRequest>(object, WITHDRAWAL_KEYS)?
+                    .map(Request::WithdrawalRequest),
+            );
+            requests.extend(
+                typed::<ConsolidationRequest>(object, CONSOLIDATION_KEYS)?
+                    .map(Request::ConsolidationRequest),
+            );
+            Ok(requests)
+        }
+        _ => Err(DebugJsonError::UnexpectedShape),
+    }
+}
+
+/// Deserializes the requests listed under the first of the given keys that is present.
+fn typed<T: for<'de> Deserialize<'de>>(
+    object: &Map<String, Value>,
+    keys: [&str; 2],
+) -> Result<impl Iterator<Item = T>, DebugJsonError> {
+    let requests = match keys.iter().find_map(|key| object.get(*key)) {
+        Some(list) => Vec::<T>::deserialize(list).map_err(DebugJsonError::Json)?,
+        None => Vec::new(),
+    };
+    Ok(requests.into_iter())
+}
+
+/// Errors returned by [`from_debug_json`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DebugJsonError {
+    /// The value is neither an array of strings nor an object.
+    UnexpectedShape,
+    /// A request of the array form could not be parsed.
+    ExecutionRequests(ExecutionRequestsError),
+    /// A request of the object form could not be deserialized.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for DebugJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedShape => {
+                f.write_str("expected an array of hex strings or an object of requests")
+            }
+            Self::ExecutionRequests(err) => err.fmt(f),
+            Self::Json(err) => write!(f, "invalid request object: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DebugJsonError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eip7002::{sample_request, SourceAddress},
+        eip7685::Encodable7685,
+        Gwei,
+    };
+    use alloy_primitives::{address, fixed_bytes, hex};
+    use serde_json::json;
+
+    fn requests() -> (WithdrawalRequest, ConsolidationRequest, [Request; 2]) {
+        let withdrawal = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let consolidation = ConsolidationRequest::default();
+        (withdrawal, consolidation, [withdrawal.into(), consolidation.into()])
+    }
+
+    #[test]
+    fn array_of_strings() {
+        // the `executionRequests` of an `engine_getPayloadV4` response with two withdrawal
+        // requests, packed into one entry, and no deposit or consolidation requests
+        let value = json!([
+            "0x01ae0e8770147aaa6828a0d6f642504663f10f7d1e8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b0000000000000162e4d06cd23f6e64a1c671b3cd4d4b8f2c66b4d8a2b02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa650000000000000000"
+        ]);
+        assert_eq!(
+            from_debug_json(&value).unwrap(),
+            [
+                Request::WithdrawalRequest(sample_request()),
+                Request::WithdrawalRequest(WithdrawalRequest {
+                    source_address: SourceAddress(address!("e4D06cD23F6e64A1C671b3cd4d4B8F2C66B4D8a2")),
+                    validator_pubkey: fixed_bytes!("b02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa65"),
+                    amount: Gwei::ZERO,
+                }),
+            ]
+        );
+        assert_eq!(from_debug_json(&json!([])).unwrap(), []);
+
+        // a single RLP-encoded request is not a valid entry
+        let (withdrawal, _, _) = requests();
+        let rlp = hex::encode_prefixed(withdrawal.encoded_7685());
+        assert!(matches!(
+            from_debug_json(&json!([rlp])),
+            Err(DebugJsonError::ExecutionRequests(ExecutionRequestsError::InvalidLength {
+                index: 0,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            from_debug_json(&json!(["0x01", "0xzz"])),
+            Err(DebugJsonError::ExecutionRequests(ExecutionRequestsError::Hex { index: 1, .. }))
+        ));
+        assert!(matches!(from_debug_json(&json!([1, 2])), Err(DebugJsonError::UnexpectedShape)));
+    }
+
+    #[test]
+    fn object_of_requests() {
+        let (withdrawal, consolidation, requests) = requests();
+
+        // types are returned in order, regardless of the key order
+        let value = json!({
+            "consolidations": [consolidation],
+            "withdrawals": [withdrawal],
+            "blockNumber": "0x1",
+        });
+        assert_eq!(from_debug_json(&value).unwrap(), requests);
+
+        let value = json!({
+            "withdrawalRequests": [withdrawal],
+            "consolidationRequests": [consolidation],
+            "depositRequests": [],
+        });
+        assert_eq!(from_debug_json(&value).unwrap(), requests);
+        assert_eq!(from_debug_json(&json!({})).unwrap(), []);
+
+        assert!(matches!(
+            from_debug_json(&json!({ "withdrawals": [consolidation] })),
+            Err(DebugJsonError::Json(_))
+        ));
+        assert!(matches!(from_debug_json(&json!("0x")), Err(DebugJsonError::UnexpectedShape)));
+    }
+}
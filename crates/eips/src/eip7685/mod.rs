@@ -0,0 +1,1867 @@
+//! [EIP-7685]: General purpose execution layer requests
+//!
+//! Contains traits for encoding and decoding EIP-7685 requests, the [`Request`] envelope over the
+//! known request types, as well as validation functions.
+//!
+//! [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+
+#[cfg(feature = "codec")]
+mod codec;
+#[cfg(feature = "codec")]
+pub use codec::FrameCodec;
+
+#[cfg(feature = "serde")]
+mod debug_json;
+#[cfg(feature = "serde")]
+pub use debug_json::{from_debug_json, DebugJsonError};
+
+#[cfg(not(feature = "std"))]
+use crate::alloc::{vec, vec::Vec};
+
+use crate::{
+    eip6110::{DepositRequest, DEPOSIT_REQUEST_PACKED_SIZE, DEPOSIT_REQUEST_TYPE},
+    eip7002::{WithdrawalRequest, WITHDRAWAL_REQUEST_PACKED_SIZE, WITHDRAWAL_REQUEST_TYPE},
+    eip7251::{
+        ConsolidationRequest, CONSOLIDATION_REQUEST_PACKED_SIZE, CONSOLIDATION_REQUEST_TYPE,
+    },
+};
+use alloy_primitives::{b256, hex, Address, Bytes, FixedBytes, B256};
+use alloy_rlp::{Buf, BufMut, Decodable, Encodable};
+#[cfg(feature = "trie")]
+use alloy_trie::{HashBuilder, Nibbles};
+use core::{
+    fmt,
+    fmt::{Display, Formatter},
+};
+
+/// [EIP-7685] decoding errors.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Eip7685Error {
+    /// Rlp error from [`alloy_rlp`].
+    RlpError(alloy_rlp::Error),
+    /// Got an unexpected request type while decoding.
+    UnexpectedType(u8),
+    /// There was no request type in the buffer.
+    MissingType,
+}
+
+impl Display for Eip7685Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RlpError(err) => write!(f, "{err}"),
+            Self::UnexpectedType(t) => write!(f, "Unexpected request type. Got {t}."),
+            Self::MissingType => write!(f, "There was no type flag"),
+        }
+    }
+}
+
+impl From<alloy_rlp::Error> for Eip7685Error {
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::RlpError(err)
+    }
+}
+
+impl From<Eip7685Error> for alloy_rlp::Error {
+    fn from(err: Eip7685Error) -> Self {
+        match err {
+            Eip7685Error::RlpError(err) => err,
+            Eip7685Error::MissingType => Self::Custom("eip7685 decoding failed: missing type"),
+            Eip7685Error::UnexpectedType(_) => {
+                Self::Custom("eip7685 decoding failed: unexpected type")
+            }
+        }
+    }
+}
+
+/// Decoding trait for [EIP-7685] requests. The trait should be implemented for an envelope that
+/// wraps each possible request type.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub trait Decodable7685: Sized {
+    /// Extract the type byte from the buffer, if any. The type byte is the
+    /// first byte.
+    fn extract_type_byte(buf: &mut &[u8]) -> Option<u8> {
+        buf.first().copied()
+    }
+
+    /// Decode the appropriate variant, based on the request type.
+    ///
+    /// This function is invoked by [`Self::decode_7685`] with the type byte, and the tail of the
+    /// buffer.
+    ///
+    /// ## Note
+    ///
+    /// This should be a simple match block that invokes an inner type's decoder. The decoder is
+    /// request type dependent.
+    fn typed_decode(ty: u8, buf: &mut &[u8]) -> Result<Self, Eip7685Error>;
+
+    /// Decode an EIP-7685 request into a concrete instance.
+    ///
+    /// On success, the buffer is advanced past the decoded request, so that consecutive requests
+    /// can be decoded from the same buffer.
+    fn decode_7685(buf: &mut &[u8]) -> Result<Self, Eip7685Error> {
+        let ty = Self::extract_type_byte(buf).ok_or(Eip7685Error::MissingType)?;
+        buf.advance(1);
+        Self::typed_decode(ty, buf)
+    }
+}
+
+/// Encoding trait for [EIP-7685] requests. The trait should be implemented for an envelope that
+/// wraps each possible request type.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub trait Encodable7685: Sized + Send + Sync + 'static {
+    /// Return the request type.
+    fn request_type(&self) -> u8;
+
+    /// Encode the request according to [EIP-7685] rules.
+    ///
+    /// First a 1-byte flag specifying the request type, then the encoded payload.
+    ///
+    /// The encoding of the payload is request-type dependent.
+    ///
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    fn encode_7685(&self, out: &mut dyn BufMut) {
+        out.put_u8(self.request_type());
+        self.encode_payload_7685(out);
+    }
+
+    /// Encode the request payload.
+    ///
+    /// The encoding for the payload is request type dependent.
+    fn encode_payload_7685(&self, out: &mut dyn BufMut);
+
+    /// Encode the request according to [EIP-7685] rules.
+    ///
+    /// First a 1-byte flag specifying the request type, then the encoded payload.
+    ///
+    /// The encoding of the payload is request-type dependent.
+    ///
+    /// This is a convenience method for encoding into a vec, and returning the
+    /// vec.
+    ///
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    fn encoded_7685(&self) -> Vec<u8> {
+        let mut out = vec![];
+        self.encode_7685(&mut out);
+        out
+    }
+}
+
+/// An [EIP-7685] request envelope, blanket implemented for types that impl
+/// [`Encodable7685`] and [`Decodable7685`]. This envelope is a wrapper around
+/// a request, differentiated by the request type.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub trait Eip7685RequestEnvelope: Decodable7685 + Encodable7685 {}
+impl<T> Eip7685RequestEnvelope for T where T: Decodable7685 + Encodable7685 {}
+
+/// Conversion of a request into its [EIP-7685] encoding, for integrating with request envelopes
+/// defined outside of this crate.
+///
+/// The emitted bytes are `request_type || request_data`, where `request_type` is a single byte and
+/// `request_data` is the RLP encoding of the request. This is the same encoding as
+/// [`Encodable7685::encoded_7685`], and is implemented for all types implementing it.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub trait IntoRequest {
+    /// Converts the request into its [EIP-7685] encoding.
+    ///
+    /// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+    fn into_request_bytes(self) -> Bytes;
+}
+
+impl<T: Encodable7685> IntoRequest for T {
+    fn into_request_bytes(self) -> Bytes {
+        self.encoded_7685().into()
+    }
+}
+
+/// Returns the [EIP-7685] request type of an encoded request, without decoding its payload.
+///
+/// Returns `None` if the buffer is empty.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub const fn request_type(raw: &[u8]) -> Option<u8> {
+    match raw {
+        [ty, ..] => Some(*ty),
+        [] => None,
+    }
+}
+
+/// Decodes the outer RLP header of the buffer, without decoding the payload.
+///
+/// This is useful to diagnose malformed request data, e.g. the `request_data` following the type
+/// byte of an encoded request. Returns [`alloy_rlp::Error::InputTooShort`] if the buffer is shorter
+/// than the payload length given by the header.
+pub fn peek_rlp_header(buf: &[u8]) -> Result<alloy_rlp::Header, alloy_rlp::Error> {
+    alloy_rlp::Header::decode(&mut &buf[..])
+}
+
+/// Decodes a concatenation of [EIP-7685] encoded requests, i.e. `request_type || rlp(request)`
+/// for each request, until the buffer is exhausted.
+///
+/// On failure, the error is paired with the byte offset of the start of the request that could
+/// not be decoded.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+pub fn decode_all_with_offsets(buf: &[u8]) -> Result<Vec<Request>, (usize, alloy_rlp::Error)> {
+    let mut remaining = buf;
+    let mut requests = Vec::new();
+    while !remaining.is_empty() {
+        let offset = buf.len() - remaining.len();
+        let request = Request::decode_7685(&mut remaining).map_err(|err| (offset, err.into()))?;
+        requests.push(request);
+    }
+    Ok(requests)
+}
+
+/// The type of an [EIP-7685] request, as given by its leading type byte.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RequestType {
+    /// An [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110) deposit request.
+    Deposit,
+    /// An [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002) withdrawal request.
+    Withdrawal,
+    /// An [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251) consolidation request.
+    Consolidation,
+    /// A request type that is not known to this crate.
+    Unknown(u8),
+}
+
+impl From<u8> for RequestType {
+    fn from(ty: u8) -> Self {
+        match ty {
+            DEPOSIT_REQUEST_TYPE => Self::Deposit,
+            WITHDRAWAL_REQUEST_TYPE => Self::Withdrawal,
+            CONSOLIDATION_REQUEST_TYPE => Self::Consolidation,
+            ty => Self::Unknown(ty),
+        }
+    }
+}
+
+impl From<RequestType> for u8 {
+    fn from(ty: RequestType) -> Self {
+        match ty {
+            RequestType::Deposit => DEPOSIT_REQUEST_TYPE,
+            RequestType::Withdrawal => WITHDRAWAL_REQUEST_TYPE,
+            RequestType::Consolidation => CONSOLIDATION_REQUEST_TYPE,
+            RequestType::Unknown(ty) => ty,
+        }
+    }
+}
+
+/// The type bytes of all request types known to this crate, with their names, in ascending order.
+pub const REQUEST_TYPES: &[(u8, &str)] = &[
+    (DEPOSIT_REQUEST_TYPE, "deposit"),
+    (WITHDRAWAL_REQUEST_TYPE, "withdrawal"),
+    (CONSOLIDATION_REQUEST_TYPE, "consolidation"),
+];
+
+/// Returns the name of the given request type byte from [`REQUEST_TYPES`], or `None` if the type
+/// is not known to this crate.
+pub fn type_name(ty: u8) -> Option<&'static str> {
+    REQUEST_TYPES.iter().find(|(known, _)| *known == ty).map(|(_, name)| *name)
+}
+
+/// Ethereum execution layer requests.
+///
+/// See also [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Request {
+    /// An [EIP-6110] deposit request.
+    ///
+    /// [EIP-6110]: https://eips.ethereum.org/EIPS/eip-6110
+    DepositRequest(DepositRequest),
+    /// An [EIP-7002] withdrawal request.
+    ///
+    /// [EIP-7002]: https://eips.ethereum.org/EIPS/eip-7002
+    WithdrawalRequest(WithdrawalRequest),
+    /// An [EIP-7251] consolidation request.
+    ///
+    /// [EIP-7251]: https://eips.ethereum.org/EIPS/eip-7251
+    ConsolidationRequest(ConsolidationRequest),
+}
+
+impl From<DepositRequest> for Request {
+    fn from(v: DepositRequest) -> Self {
+        Self::DepositRequest(v)
+    }
+}
+
+impl From<WithdrawalRequest> for Request {
+    fn from(v: WithdrawalRequest) -> Self {
+        Self::WithdrawalRequest(v)
+    }
+}
+
+impl From<ConsolidationRequest> for Request {
+    fn from(v: ConsolidationRequest) -> Self {
+        Self::ConsolidationRequest(v)
+    }
+}
+
+impl TryFrom<Request> for DepositRequest {
+    type Error = Request;
+
+    fn try_from(request: Request) -> Result<Self, Self::Error> {
+        match request {
+            Request::DepositRequest(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+impl TryFrom<Request> for WithdrawalRequest {
+    type Error = Request;
+
+    fn try_from(request: Request) -> Result<Self, Self::Error> {
+        match request {
+            Request::WithdrawalRequest(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+impl TryFrom<Request> for ConsolidationRequest {
+    type Error = Request;
+
+    fn try_from(request: Request) -> Result<Self, Self::Error> {
+        match request {
+            Request::ConsolidationRequest(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+impl Request {
+    /// Whether this is a [`DepositRequest`].
+    pub const fn is_deposit_request(&self) -> bool {
+        matches!(self, Self::DepositRequest(_))
+    }
+
+    /// Whether this is a [`WithdrawalRequest`].
+    pub const fn is_withdrawal_request(&self) -> bool {
+        matches!(self, Self::WithdrawalRequest(_))
+    }
+
+    /// Whether this is a [`ConsolidationRequest`].
+    pub const fn is_consolidation_request(&self) -> bool {
+        matches!(self, Self::ConsolidationRequest(_))
+    }
+
+    /// Return the inner [`DepositRequest`], or `None` of this is not a deposit request.
+    pub const fn as_deposit_request(&self) -> Option<&DepositRequest> {
+        match self {
+            Self::DepositRequest(req) => Some(req),
+            _ => None,
+        }
+    }
+
+    /// Return the inner [`WithdrawalRequest`], or `None` if this is not a withdrawal request.
+    pub const fn as_withdrawal_request(&self) -> Option<&WithdrawalRequest> {
+        match self {
+            Self::WithdrawalRequest(req) => Some(req),
+            _ => None,
+        }
+    }
+
+    /// Return the inner [`ConsolidationRequest`], or `None` if this is not a consolidation request.
+    pub const fn as_consolidation_request(&self) -> Option<&ConsolidationRequest> {
+        match self {
+            Self::ConsolidationRequest(req) => Some(req),
+            _ => None,
+        }
+    }
+}
+
+impl Encodable7685 for Request {
+    fn request_type(&self) -> u8 {
+        match self {
+            Self::DepositRequest(_) => DEPOSIT_REQUEST_TYPE,
+            Self::WithdrawalRequest(_) => WITHDRAWAL_REQUEST_TYPE,
+            Self::ConsolidationRequest(_) => CONSOLIDATION_REQUEST_TYPE,
+        }
+    }
+
+    fn encode_payload_7685(&self, out: &mut dyn alloy_rlp::BufMut) {
+        match self {
+            Self::DepositRequest(deposit) => deposit.encode(out),
+            Self::WithdrawalRequest(withdrawal) => withdrawal.encode(out),
+            Self::ConsolidationRequest(consolidation) => consolidation.encode(out),
+        }
+    }
+}
+
+impl Encodable7685 for DepositRequest {
+    fn request_type(&self) -> u8 {
+        DEPOSIT_REQUEST_TYPE
+    }
+
+    fn encode_payload_7685(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.encode(out);
+    }
+}
+
+impl Encodable7685 for WithdrawalRequest {
+    fn request_type(&self) -> u8 {
+        WITHDRAWAL_REQUEST_TYPE
+    }
+
+    fn encode_payload_7685(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.encode(out);
+    }
+}
+
+impl Encodable7685 for ConsolidationRequest {
+    fn request_type(&self) -> u8 {
+        CONSOLIDATION_REQUEST_TYPE
+    }
+
+    fn encode_payload_7685(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.encode(out);
+    }
+}
+
+/// A request type with a fixed-width packed encoding, as returned by the system contracts that
+/// produce requests.
+///
+/// In the packed encoding, the fields of the request are concatenated in declaration order without
+/// any length prefixes.
+pub trait PackedRequest: Sized {
+    /// The length in bytes of the packed encoding.
+    const PACKED_LEN: usize;
+
+    /// Appends the packed encoding of the request to the buffer.
+    fn encode_packed(&self, out: &mut dyn BufMut);
+
+    /// Decodes a request from its packed encoding.
+    ///
+    /// Returns an error if the buffer is not exactly [`Self::PACKED_LEN`] bytes long.
+    fn from_packed(packed: &[u8]) -> Result<Self, PackedLengthError>;
+
+    /// Returns the packed encoding of the request.
+    fn to_packed(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::PACKED_LEN);
+        self.encode_packed(&mut out);
+        out
+    }
+}
+
+/// Error returned by [`PackedRequest::from_packed`] when the packed encoding has the wrong length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PackedLengthError {
+    /// The expected length.
+    pub expected: usize,
+    /// The actual length.
+    pub got: usize,
+}
+
+impl PackedLengthError {
+    /// Returns an error if `got` is not `expected`.
+    const fn check(expected: usize, got: usize) -> Result<(), Self> {
+        if expected == got {
+            Ok(())
+        } else {
+            Err(Self { expected, got })
+        }
+    }
+}
+
+impl Display for PackedLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid packed request length {}, expected {}", self.got, self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackedLengthError {}
+
+const _: () = {
+    use core::mem::size_of;
+
+    assert!(
+        DepositRequest::PACKED_LEN
+            == size_of::<FixedBytes<48>>()
+                + size_of::<B256>()
+                + size_of::<u64>()
+                + size_of::<FixedBytes<96>>()
+                + size_of::<u64>()
+    );
+    assert!(
+        WithdrawalRequest::PACKED_LEN
+            == size_of::<Address>() + size_of::<FixedBytes<48>>() + size_of::<u64>()
+    );
+    assert!(
+        ConsolidationRequest::PACKED_LEN
+            == size_of::<Address>() + size_of::<FixedBytes<48>>() + size_of::<FixedBytes<48>>()
+    );
+};
+
+/// The amount and index are encoded as little-endian integers, matching the `DepositEvent` log of
+/// the deposit contract.
+impl PackedRequest for DepositRequest {
+    const PACKED_LEN: usize = DEPOSIT_REQUEST_PACKED_SIZE;
+
+    fn encode_packed(&self, out: &mut dyn BufMut) {
+        out.put_slice(self.pubkey.as_slice());
+        out.put_slice(self.withdrawal_credentials.as_slice());
+        out.put_u64_le(self.amount);
+        out.put_slice(self.signature.as_slice());
+        out.put_u64_le(self.index);
+    }
+
+    fn from_packed(mut packed: &[u8]) -> Result<Self, PackedLengthError> {
+        PackedLengthError::check(Self::PACKED_LEN, packed.len())?;
+        let pubkey = FixedBytes::from_slice(&packed[..48]);
+        let withdrawal_credentials = B256::from_slice(&packed[48..80]);
+        packed.advance(80);
+        let amount = packed.get_u64_le();
+        let signature = FixedBytes::from_slice(&packed[..96]);
+        packed.advance(96);
+        let index = packed.get_u64_le();
+        Ok(Self { pubkey, withdrawal_credentials, amount, signature, index })
+    }
+}
+
+/// The amount is encoded as a big-endian integer, matching the output of the withdrawal requests
+/// contract.
+impl PackedRequest for WithdrawalRequest {
+    const PACKED_LEN: usize = WITHDRAWAL_REQUEST_PACKED_SIZE;
+
+    fn encode_packed(&self, out: &mut dyn BufMut) {
+        out.put_slice(self.source_address.0.as_slice());
+        out.put_slice(self.validator_pubkey.as_slice());
+        out.put_u64(self.amount.0);
+    }
+
+    fn from_packed(packed: &[u8]) -> Result<Self, PackedLengthError> {
+        PackedLengthError::check(Self::PACKED_LEN, packed.len())?;
+        Ok(Self::from_packed_unchecked(packed))
+    }
+}
+
+impl PackedRequest for ConsolidationRequest {
+    const PACKED_LEN: usize = CONSOLIDATION_REQUEST_PACKED_SIZE;
+
+    fn encode_packed(&self, out: &mut dyn BufMut) {
+        out.put_slice(self.source_address.0.as_slice());
+        out.put_slice(self.source_pubkey.as_slice());
+        out.put_slice(self.target_pubkey.as_slice());
+    }
+
+    fn from_packed(packed: &[u8]) -> Result<Self, PackedLengthError> {
+        PackedLengthError::check(Self::PACKED_LEN, packed.len())?;
+        Ok(Self {
+            source_address: Address::from_slice(&packed[..20]),
+            source_pubkey: FixedBytes::from_slice(&packed[20..68]),
+            target_pubkey: FixedBytes::from_slice(&packed[68..]),
+        })
+    }
+}
+
+impl Decodable7685 for Request {
+    fn typed_decode(ty: u8, buf: &mut &[u8]) -> Result<Self, Eip7685Error> {
+        Ok(match ty {
+            DEPOSIT_REQUEST_TYPE => Self::DepositRequest(DepositRequest::decode(buf)?),
+            WITHDRAWAL_REQUEST_TYPE => Self::WithdrawalRequest(WithdrawalRequest::decode(buf)?),
+            CONSOLIDATION_REQUEST_TYPE => {
+                Self::ConsolidationRequest(ConsolidationRequest::decode(buf)?)
+            }
+            ty => return Err(Eip7685Error::UnexpectedType(ty)),
+        })
+    }
+}
+
+/// An execution layer request with a serde representation tagged by the request type, e.g.
+/// `{"type":"withdrawal",...}`.
+///
+/// Unlike the untagged serde representation of [`Request`], this is unambiguous, which makes it
+/// suitable for storing different request types together.
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TaggedRequest {
+    /// An [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110) deposit request.
+    Deposit(DepositRequest),
+    /// An [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002) withdrawal request.
+    Withdrawal(WithdrawalRequest),
+    /// An [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251) consolidation request.
+    Consolidation(ConsolidationRequest),
+}
+
+#[cfg(feature = "serde")]
+impl From<Request> for TaggedRequest {
+    fn from(request: Request) -> Self {
+        match request {
+            Request::DepositRequest(v) => Self::Deposit(v),
+            Request::WithdrawalRequest(v) => Self::Withdrawal(v),
+            Request::ConsolidationRequest(v) => Self::Consolidation(v),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TaggedRequest> for Request {
+    fn from(request: TaggedRequest) -> Self {
+        match request {
+            TaggedRequest::Deposit(v) => Self::DepositRequest(v),
+            TaggedRequest::Withdrawal(v) => Self::WithdrawalRequest(v),
+            TaggedRequest::Consolidation(v) => Self::ConsolidationRequest(v),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<DepositRequest> for TaggedRequest {
+    fn from(v: DepositRequest) -> Self {
+        Self::Deposit(v)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TaggedRequest> for DepositRequest {
+    type Error = TaggedRequest;
+
+    fn try_from(request: TaggedRequest) -> Result<Self, Self::Error> {
+        match request {
+            TaggedRequest::Deposit(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<WithdrawalRequest> for TaggedRequest {
+    fn from(v: WithdrawalRequest) -> Self {
+        Self::Withdrawal(v)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TaggedRequest> for WithdrawalRequest {
+    type Error = TaggedRequest;
+
+    fn try_from(request: TaggedRequest) -> Result<Self, Self::Error> {
+        match request {
+            TaggedRequest::Withdrawal(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ConsolidationRequest> for TaggedRequest {
+    fn from(v: ConsolidationRequest) -> Self {
+        Self::Consolidation(v)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TaggedRequest> for ConsolidationRequest {
+    type Error = TaggedRequest;
+
+    fn try_from(request: TaggedRequest) -> Result<Self, Self::Error> {
+        match request {
+            TaggedRequest::Consolidation(v) => Ok(v),
+            request => Err(request),
+        }
+    }
+}
+
+/// An [EIP-7685] request in its canonical `request_type || request_data` encoding, regardless of
+/// its concrete type.
+///
+/// Requests are compared, ordered and hashed by their raw bytes.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct PrefixedRequestBytes(pub Bytes);
+
+impl PrefixedRequestBytes {
+    /// Returns the request type, or `None` if the bytes are empty.
+    pub fn request_type(&self) -> Option<u8> {
+        request_type(self.0.as_ref())
+    }
+
+    /// Returns the encoded request, including its request type.
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    /// Decodes the request into a [`Request`].
+    ///
+    /// Returns an error if the request type is unknown, or if the bytes are not exactly one
+    /// encoded request.
+    pub fn try_decode(&self) -> Result<Request, Eip7685Error> {
+        let mut buf = self.0.as_ref();
+        let request = Request::decode_7685(&mut buf)?;
+        if !buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength.into());
+        }
+        Ok(request)
+    }
+}
+
+impl From<Bytes> for PrefixedRequestBytes {
+    fn from(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Request> for PrefixedRequestBytes {
+    fn from(request: Request) -> Self {
+        Self(request.encoded_7685().into())
+    }
+}
+
+impl AsRef<[u8]> for PrefixedRequestBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The request data of one [EIP-7685] request type, prefixed with its type: `request_type ||
+/// request_data`, where `request_data` is the concatenation of all [packed](PackedRequest)
+/// requests of that type in a block.
+///
+/// This is the input of the [`requests_hash`] commitment and the form in which requests appear in
+/// the `executionRequests` of Prague engine API payloads. Unlike [`PrefixedRequestBytes`], it
+/// holds any number of requests of the same type, and is not RLP-encoded.
+///
+/// [EIP-7685]: https://eips.ethereum.org/EIPS/eip-7685
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct TypedRequestData(pub Bytes);
+
+impl TypedRequestData {
+    /// Concatenates the packed encodings of the given requests, prefixed with `ty`.
+    ///
+    /// Returns `None` if there are no requests, since request types without any request data are
+    /// left out of the commitment.
+    pub fn from_packed<T: PackedRequest>(ty: u8, requests: &[T]) -> Option<Self> {
+        if requests.is_empty() {
+            return None;
+        }
+        let mut out = Vec::with_capacity(1 + requests.len() * T::PACKED_LEN);
+        out.push(ty);
+        for request in requests {
+            request.encode_packed(&mut out);
+        }
+        Some(Self(out.into()))
+    }
+
+    /// Returns the request type, or `None` if the bytes are empty.
+    pub fn request_type(&self) -> Option<u8> {
+        request_type(self.0.as_ref())
+    }
+
+    /// Returns the request data, without its request type.
+    pub fn request_data(&self) -> &[u8] {
+        self.0.get(1..).unwrap_or_default()
+    }
+
+    /// Returns the request type and data.
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+impl From<Bytes> for TypedRequestData {
+    fn from(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for TypedRequestData {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The [`requests_hash`] of a block without any requests: `sha256("")`.
+pub const EMPTY_REQUESTS_HASH: B256 =
+    b256!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+
+/// Calculates the flat requests commitment of a block, which replaced the
+/// `Requests::requests_root` trie root in the final Prague specification.
+///
+/// Each element is the [`TypedRequestData`] of one request type, and the elements are in ascending
+/// order of their request type. The commitment is `sha256(sha256(requests[0]) ||
+/// sha256(requests[1]) || ...)`, skipping request types without any request data.
+///
+/// See also [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685#block-header).
+#[cfg(feature = "sha2")]
+pub fn requests_hash(requests: &[TypedRequestData]) -> B256 {
+    use sha2::{Digest, Sha256};
+
+    let mut hash = Sha256::new();
+    for request in requests.iter().filter(|request| request.0.len() > 1) {
+        hash.update(Sha256::digest(&request.0));
+    }
+    B256::new(hash.finalize().into())
+}
+
+/// Assembles the requests of a block from its deposits, withdrawals and consolidations, and
+/// computes their [`requests_hash`] commitment.
+///
+/// The returned [`Requests`] are ordered by ascending request type, preserving the order of
+/// requests of the same type. The request data of each type is the concatenation of the
+/// [packed](PackedRequest) requests, and types without any requests are left out of the
+/// commitment.
+#[cfg(feature = "sha2")]
+pub fn assemble_block_requests(
+    deposits: &[DepositRequest],
+    withdrawals: &[WithdrawalRequest],
+    consolidations: &[ConsolidationRequest],
+) -> (Requests, B256) {
+    let flat: Vec<_> = [
+        TypedRequestData::from_packed(DEPOSIT_REQUEST_TYPE, deposits),
+        TypedRequestData::from_packed(WITHDRAWAL_REQUEST_TYPE, withdrawals),
+        TypedRequestData::from_packed(CONSOLIDATION_REQUEST_TYPE, consolidations),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    debug_assert_eq!(validate_ordering(&flat), Ok(()));
+
+    let requests = deposits
+        .iter()
+        .copied()
+        .map(Request::DepositRequest)
+        .chain(withdrawals.iter().copied().map(Request::WithdrawalRequest))
+        .chain(consolidations.iter().copied().map(Request::ConsolidationRequest))
+        .collect();
+    (Requests(requests), requests_hash(&flat))
+}
+
+/// Validates that the given request data is ordered by strictly ascending request type, and that
+/// none of it is empty, as required for the [`requests_hash`] commitment and the
+/// `executionRequests` of the Engine API.
+///
+/// Each request type may only appear once, since all requests of a type are concatenated into a
+/// single element. Returns the first violation, including the index of the offending element.
+pub fn validate_ordering(requests: &[TypedRequestData]) -> Result<(), OrderingError> {
+    let mut previous = None;
+    for (index, request) in requests.iter().enumerate() {
+        if request.0.len() <= 1 {
+            return Err(OrderingError::EmptyRequest { index });
+        }
+        let ty = request.0[0];
+        match previous {
+            Some(previous) if ty == previous => {
+                return Err(OrderingError::DuplicateType { index });
+            }
+            Some(previous) if ty < previous => return Err(OrderingError::OutOfOrder { index }),
+            _ => {}
+        }
+        previous = Some(ty);
+    }
+    Ok(())
+}
+
+/// Errors returned by [`validate_ordering`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderingError {
+    /// The request has a lower request type than the request preceding it.
+    OutOfOrder {
+        /// The index of the request.
+        index: usize,
+    },
+    /// The request has the same request type as the request preceding it.
+    DuplicateType {
+        /// The index of the request.
+        index: usize,
+    },
+    /// The request has no request data.
+    EmptyRequest {
+        /// The index of the request.
+        index: usize,
+    },
+}
+
+impl OrderingError {
+    /// Returns the index of the offending request.
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::OutOfOrder { index }
+            | Self::DuplicateType { index }
+            | Self::EmptyRequest { index } => *index,
+        }
+    }
+}
+
+impl Display for OrderingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfOrder { index } => {
+                write!(f, "request {index} is not ordered by ascending request type")
+            }
+            Self::DuplicateType { index } => {
+                write!(f, "request {index} repeats the request type of the previous request")
+            }
+            Self::EmptyRequest { index } => write!(f, "request {index} has no request data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OrderingError {}
+
+/// Parses the `executionRequests` of an `engine_getPayloadV4` response, where each entry is the
+/// `0x`-prefixed hex string of a [`TypedRequestData`]: a request type followed by the
+/// concatenated [packed](PackedRequest) requests of that type.
+///
+/// The entries must be in strictly ascending order of their request type and must not be empty,
+/// see [`validate_ordering`]. The requests are returned in the order of the entries, preserving
+/// the order of requests of the same type. An error is returned for the first entry that is not
+/// valid hex, violates the ordering, has an unknown request type, or whose request data is not a
+/// whole number of packed requests.
+pub fn parse_execution_requests<S: AsRef<str>>(
+    list: &[S],
+) -> Result<Vec<Request>, ExecutionRequestsError> {
+    fn unpack<T: PackedRequest + Into<Request>>(
+        index: usize,
+        data: &[u8],
+        out: &mut Vec<Request>,
+    ) -> Result<(), ExecutionRequestsError> {
+        if data.len() % T::PACKED_LEN != 0 {
+            return Err(ExecutionRequestsError::InvalidLength { index, len: data.len() });
+        }
+        for packed in data.chunks_exact(T::PACKED_LEN) {
+            out.push(T::from_packed(packed).expect("chunk has the packed length").into());
+        }
+        Ok(())
+    }
+
+    let list = list
+        .iter()
+        .enumerate()
+        .map(|(index, data)| {
+            hex::decode(data.as_ref())
+                .map(|bytes| TypedRequestData(bytes.into()))
+                .map_err(|err| ExecutionRequestsError::Hex { index, err })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    validate_ordering(&list).map_err(ExecutionRequestsError::Ordering)?;
+
+    let mut requests = Vec::new();
+    for (index, data) in list.iter().enumerate() {
+        let payload = data.request_data();
+        match data.0[0] {
+            DEPOSIT_REQUEST_TYPE => unpack::<DepositRequest>(index, payload, &mut requests)?,
+            WITHDRAWAL_REQUEST_TYPE => unpack::<WithdrawalRequest>(index, payload, &mut requests)?,
+            CONSOLIDATION_REQUEST_TYPE => {
+                unpack::<ConsolidationRequest>(index, payload, &mut requests)?
+            }
+            ty => return Err(ExecutionRequestsError::UnknownType { index, ty }),
+        }
+    }
+    Ok(requests)
+}
+
+/// Errors returned by [`parse_execution_requests`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ExecutionRequestsError {
+    /// The entry is not valid hex.
+    Hex {
+        /// The index of the entry.
+        index: usize,
+        /// The hex decoding error.
+        err: hex::FromHexError,
+    },
+    /// The entry is empty, or the entries are not in strictly ascending order of their request
+    /// type.
+    Ordering(OrderingError),
+    /// The entry has an unknown request type.
+    UnknownType {
+        /// The index of the entry.
+        index: usize,
+        /// The request type.
+        ty: u8,
+    },
+    /// The request data of the entry is not a multiple of the packed length of its type.
+    InvalidLength {
+        /// The index of the entry.
+        index: usize,
+        /// The length of the request data, without the request type.
+        len: usize,
+    },
+}
+
+impl ExecutionRequestsError {
+    /// Returns the index of the offending entry.
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::Hex { index, .. }
+            | Self::UnknownType { index, .. }
+            | Self::InvalidLength { index, .. } => *index,
+            Self::Ordering(err) => err.index(),
+        }
+    }
+}
+
+impl Display for ExecutionRequestsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hex { index, err } => {
+                write!(f, "execution request {index} is not valid hex: {err}")
+            }
+            Self::Ordering(err) => write!(f, "invalid execution requests: {err}"),
+            Self::UnknownType { index, ty } => {
+                write!(f, "execution request {index} has unknown request type {ty}")
+            }
+            Self::InvalidLength { index, len } => write!(
+                f,
+                "execution request {index} has {len} bytes of request data, which is not a whole \
+                 number of packed requests"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExecutionRequestsError {}
+
+/// A list of execution layer [`Request`]s.
+///
+/// See also [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Requests(pub Vec<Request>);
+
+impl From<Vec<Request>> for Requests {
+    fn from(requests: Vec<Request>) -> Self {
+        Self(requests)
+    }
+}
+
+impl Requests {
+    /// Creates an empty list with space for at least `capacity` requests.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Adds a new request to the list.
+    pub fn push(&mut self, request: Request) {
+        self.0.push(request);
+    }
+
+    /// Returns the number of requests in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list contains no requests.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the requests.
+    pub fn iter(&self) -> core::slice::Iter<'_, Request> {
+        self.0.iter()
+    }
+
+    /// Calculates the requests root of the list, as committed to by the block header.
+    ///
+    /// This is the root of the ordered Merkle-Patricia trie over the EIP-7685 encodings of the
+    /// requests, as yielded by [`Self::iter_encoded`].
+    ///
+    /// **Note:** the requests are reordered by their request type before computing the root,
+    /// preserving the relative order of requests of the same type. The root of an unordered list
+    /// is therefore the root of its ordered counterpart, not an error: a list that does not
+    /// follow the block's request order is not rejected. Check the order separately when it
+    /// matters, e.g. when validating requests received from a peer.
+    #[cfg(feature = "trie")]
+    pub fn requests_root(&self) -> B256 {
+        let encoded: Vec<Bytes> = self.iter_encoded().collect();
+        ordered_trie_root(&encoded)
+    }
+
+    /// Returns an iterator over the `request_type || rlp(request)` encodings of the requests, in
+    /// canonical order.
+    ///
+    /// Requests are ordered by their request type, preserving the relative order of requests of
+    /// the same type. The list is scanned once per request type instead of being sorted, so
+    /// nothing is allocated up front, and each request is only encoded when the iterator reaches
+    /// it.
+    ///
+    /// These are the leaves of `Self::requests_root`. They are one RLP-encoded request each, and
+    /// are **not** the input of the final Prague [`requests_hash`] commitment, which hashes the
+    /// concatenated packed requests of each type instead. Use [`Self::requests_hash`] for that.
+    pub fn iter_encoded(&self) -> impl Iterator<Item = Bytes> + '_ {
+        [DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE, CONSOLIDATION_REQUEST_TYPE]
+            .into_iter()
+            .flat_map(move |ty| self.0.iter().filter(move |request| request.request_type() == ty))
+            .map(|request| request.encoded_7685().into())
+    }
+
+    /// Calculates the flat [`requests_hash`] commitment of the list, as committed to by the final
+    /// Prague block header.
+    ///
+    /// The requests are grouped by their request type, preserving the relative order of requests
+    /// of the same type, and the [packed](PackedRequest) requests of each type are hashed as one
+    /// [`TypedRequestData`]. See [`assemble_block_requests`].
+    #[cfg(feature = "sha2")]
+    pub fn requests_hash(&self) -> B256 {
+        let mut builder = RequestsBuilder::default();
+        for request in self.iter() {
+            builder.push(*request);
+        }
+        builder.build_with_hash().1
+    }
+
+    /// Returns the total length of the encodings yielded by [`Self::iter_encoded`], i.e. the sum of
+    /// `1 + rlp_length` over all requests, without encoding them.
+    pub fn encoded_len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|request| {
+                1 + match request {
+                    Request::DepositRequest(deposit) => deposit.length(),
+                    Request::WithdrawalRequest(withdrawal) => withdrawal.length(),
+                    Request::ConsolidationRequest(consolidation) => consolidation.length(),
+                }
+            })
+            .sum()
+    }
+}
+
+/// A builder for the [`Requests`] of a block, which orders requests by type.
+///
+/// Requests of each type are collected separately, preserving their relative order, so that the
+/// final list and the buffers of its commitment can be allocated at their exact sizes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestsBuilder {
+    deposits: Vec<DepositRequest>,
+    withdrawals: Vec<WithdrawalRequest>,
+    consolidations: Vec<ConsolidationRequest>,
+}
+
+impl RequestsBuilder {
+    /// Creates an empty builder with space for the given number of requests of each type.
+    pub fn with_capacity(deposits: usize, withdrawals: usize, consolidations: usize) -> Self {
+        Self {
+            deposits: Vec::with_capacity(deposits),
+            withdrawals: Vec::with_capacity(withdrawals),
+            consolidations: Vec::with_capacity(consolidations),
+        }
+    }
+
+    /// Adds a request.
+    pub fn push(&mut self, request: impl Into<Request>) {
+        match request.into() {
+            Request::DepositRequest(request) => self.deposits.push(request),
+            Request::WithdrawalRequest(request) => self.withdrawals.push(request),
+            Request::ConsolidationRequest(request) => self.consolidations.push(request),
+        }
+    }
+
+    /// Returns the number of requests of the given type.
+    pub fn count(&self, ty: RequestType) -> usize {
+        match ty {
+            RequestType::Deposit => self.deposits.len(),
+            RequestType::Withdrawal => self.withdrawals.len(),
+            RequestType::Consolidation => self.consolidations.len(),
+            RequestType::Unknown(_) => 0,
+        }
+    }
+
+    /// Returns the total number of requests.
+    pub fn len(&self) -> usize {
+        self.deposits.len() + self.withdrawals.len() + self.consolidations.len()
+    }
+
+    /// Returns `true` if no requests have been added.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the requests ordered by ascending request type.
+    pub fn build(self) -> Requests {
+        let mut requests = Requests::with_capacity(self.len());
+        requests.0.extend(self.deposits.into_iter().map(Request::DepositRequest));
+        requests.0.extend(self.withdrawals.into_iter().map(Request::WithdrawalRequest));
+        requests.0.extend(self.consolidations.into_iter().map(Request::ConsolidationRequest));
+        requests
+    }
+
+    /// Returns the requests ordered by ascending request type, together with their
+    /// [`requests_hash`] commitment. See [`assemble_block_requests`].
+    #[cfg(feature = "sha2")]
+    pub fn build_with_hash(self) -> (Requests, B256) {
+        assemble_block_requests(&self.deposits, &self.withdrawals, &self.consolidations)
+    }
+}
+
+/// Calculates the root of an ordered Merkle-Patricia trie, keyed by the RLP-encoded index of each
+/// item.
+#[cfg(feature = "trie")]
+fn ordered_trie_root<T: AsRef<[u8]>>(items: &[T]) -> B256 {
+    let mut hb = HashBuilder::default();
+    let mut key = Vec::new();
+    let items_len = items.len();
+    for i in 0..items_len {
+        // leaves must be added in nibble order, so the RLP encoded index has to be adjusted
+        let index = adjust_index_for_rlp(i, items_len);
+        key.clear();
+        index.encode(&mut key);
+        hb.add_leaf(Nibbles::unpack(&key), items[index].as_ref());
+    }
+    hb.root()
+}
+
+/// Adjusts the index of an item for RLP encoding, so that the encoded keys are iterated in sorted
+/// order.
+#[cfg(feature = "trie")]
+const fn adjust_index_for_rlp(i: usize, len: usize) -> usize {
+    if i > 0x7f {
+        i
+    } else if i == 0x7f || i + 1 == len {
+        0
+    } else {
+        i + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eip7002::{sample_request, SourceAddress},
+        Gwei,
+    };
+    use alloy_primitives::{address, bytes, fixed_bytes};
+
+    #[test]
+    fn parse_get_payload_v4_execution_requests() {
+        let response: serde_json::Value = serde_json::from_str(
+            r#"{
+                "executionPayload": {},
+                "blockValue": "0x0",
+                "blobsBundle": { "commitments": [], "proofs": [], "blobs": [] },
+                "shouldOverrideBuilder": false,
+                "executionRequests": [
+                    "0x00b02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa65010000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e00405973070000006e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d4bf5122f344554c53bde2ebb8cd2b7e3d1600ad631c385a5d7cce23c7785459adbc1b4c900ffe48d575b5da5c638040125f65db0fe3e24494b76ea986457d986d204000000000000",
+                    "0x01ae0e8770147aaa6828a0d6f642504663f10f7d1e8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b0000000000000162e4d06cd23f6e64a1c671b3cd4d4b8f2c66b4d8a2b02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa650000000000000000",
+                    "0x02ae0e8770147aaa6828a0d6f642504663f10f7d1e8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793bb02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa65"
+                ]
+            }"#,
+        )
+        .unwrap();
+        let list: Vec<String> = response["executionRequests"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|request| request.as_str().unwrap().to_string())
+            .collect();
+
+        let source_address = address!("AE0E8770147AaA6828a0D6f642504663F10F7d1E");
+        let validator_pubkey = fixed_bytes!("8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b");
+        let deposit_pubkey = fixed_bytes!("b02f1cc5f4a07ed23a6e4c9ad0ae1bec1f5f7cd69bf12b74fd09513d362f703d071ce1b8e0eff16b0303bd266862aa65");
+        assert_eq!(
+            parse_execution_requests(&list).unwrap(),
+            [
+                Request::DepositRequest(DepositRequest {
+                    pubkey: deposit_pubkey,
+                    withdrawal_credentials: b256!("010000000000000000000000ae0e8770147aaa6828a0d6f642504663f10f7d1e"),
+                    amount: 32_000_000_000,
+                    signature: fixed_bytes!("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d4bf5122f344554c53bde2ebb8cd2b7e3d1600ad631c385a5d7cce23c7785459adbc1b4c900ffe48d575b5da5c638040125f65db0fe3e24494b76ea986457d986"),
+                    index: 1234,
+                }),
+                Request::WithdrawalRequest(sample_request()),
+                Request::WithdrawalRequest(WithdrawalRequest {
+                    source_address: SourceAddress(address!(
+                        "e4D06cD23F6e64A1C671b3cd4d4B8F2C66B4D8a2"
+                    )),
+                    validator_pubkey: deposit_pubkey,
+                    amount: Gwei::ZERO,
+                }),
+                Request::ConsolidationRequest(ConsolidationRequest {
+                    source_address,
+                    source_pubkey: validator_pubkey,
+                    target_pubkey: deposit_pubkey,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_execution_requests_invalid() {
+        assert_eq!(parse_execution_requests::<String>(&[]).unwrap(), []);
+
+        let withdrawals = |count: usize| {
+            let mut data = vec![WITHDRAWAL_REQUEST_TYPE];
+            for _ in 0..count {
+                WithdrawalRequest::default().encode_packed(&mut data);
+            }
+            hex::encode_prefixed(data)
+        };
+        assert_eq!(parse_execution_requests(&[withdrawals(2)]).unwrap().len(), 2);
+
+        let err = parse_execution_requests(&[withdrawals(1), "0x03aa".into()]).unwrap_err();
+        assert!(matches!(err, ExecutionRequestsError::UnknownType { index: 1, ty: 0x03 }));
+        assert_eq!(err.to_string(), "execution request 1 has unknown request type 3");
+
+        let truncated = withdrawals(2);
+        let truncated = &truncated[..truncated.len() - 2];
+        let err = parse_execution_requests(&[truncated]).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionRequestsError::InvalidLength { index: 0, len }
+                if len == 2 * WITHDRAWAL_REQUEST_PACKED_SIZE - 1
+        ));
+
+        let err = parse_execution_requests(&["0x01"]).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionRequestsError::Ordering(OrderingError::EmptyRequest { index: 0 })
+        ));
+        let err = parse_execution_requests(&[withdrawals(1), withdrawals(1)]).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionRequestsError::Ordering(OrderingError::DuplicateType { index: 1 })
+        ));
+        assert_eq!(err.index(), 1);
+        let consolidation = hex::encode_prefixed(
+            [&[CONSOLIDATION_REQUEST_TYPE][..], &ConsolidationRequest::default().to_packed()]
+                .concat(),
+        );
+        let err = parse_execution_requests(&[consolidation, withdrawals(1)]).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionRequestsError::Ordering(OrderingError::OutOfOrder { index: 1 })
+        ));
+
+        let err = parse_execution_requests(&[withdrawals(1), "0xzz".into()]).unwrap_err();
+        assert!(matches!(err, ExecutionRequestsError::Hex { index: 1, .. }));
+    }
+
+    fn packed_roundtrip<T>(len: usize)
+    where
+        T: PackedRequest + for<'a> arbitrary::Arbitrary<'a> + fmt::Debug + PartialEq,
+    {
+        assert_eq!(T::PACKED_LEN, len);
+        let mut u = arbitrary::Unstructured::new(&[0xab; 1024]);
+        let request = T::arbitrary(&mut u).unwrap();
+        let packed = request.to_packed();
+        assert_eq!(packed.len(), T::PACKED_LEN);
+        assert_eq!(T::from_packed(&packed), Ok(request));
+        assert_eq!(
+            T::from_packed(&packed[1..]),
+            Err(PackedLengthError { expected: len, got: len - 1 })
+        );
+    }
+
+    #[test]
+    fn packed_requests() {
+        packed_roundtrip::<DepositRequest>(192);
+        packed_roundtrip::<WithdrawalRequest>(76);
+        packed_roundtrip::<ConsolidationRequest>(116);
+
+        let deposit = DepositRequest { amount: 1, index: 2, ..Default::default() };
+        let packed = deposit.to_packed();
+        assert_eq!(packed[80..88], 1u64.to_le_bytes());
+        assert_eq!(packed[184..], 2u64.to_le_bytes());
+
+        let withdrawal = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        assert_eq!(withdrawal.to_packed(), withdrawal.to_compact());
+    }
+
+    #[test]
+    fn request_conversions() {
+        let deposit = DepositRequest {
+            pubkey: FixedBytes::with_last_byte(1),
+            withdrawal_credentials: B256::with_last_byte(2),
+            amount: 32_000_000_000,
+            signature: FixedBytes::with_last_byte(3),
+            index: 4,
+        };
+        let withdrawal = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let consolidation = ConsolidationRequest {
+            source_address: Address::with_last_byte(5),
+            ..Default::default()
+        };
+
+        let request = Request::from(deposit);
+        assert_eq!(request, Request::DepositRequest(deposit));
+        assert_eq!(DepositRequest::try_from(request), Ok(deposit));
+        assert_eq!(WithdrawalRequest::try_from(request), Err(request));
+
+        let request = Request::from(withdrawal);
+        assert_eq!(request, Request::WithdrawalRequest(withdrawal));
+        assert_eq!(WithdrawalRequest::try_from(request), Ok(withdrawal));
+        assert_eq!(ConsolidationRequest::try_from(request), Err(request));
+
+        let request = Request::from(consolidation);
+        assert_eq!(request, Request::ConsolidationRequest(consolidation));
+        assert_eq!(ConsolidationRequest::try_from(request), Ok(consolidation));
+        assert_eq!(DepositRequest::try_from(request), Err(request));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_tagged_request() {
+        let withdrawal = sample_request();
+        let consolidation = ConsolidationRequest {
+            source_address: withdrawal.source_address.0,
+            source_pubkey: withdrawal.validator_pubkey,
+            target_pubkey: FixedBytes::ZERO,
+        };
+        let cases = [
+            (
+                TaggedRequest::from(DepositRequest::default()),
+                r#"{"type":"deposit","pubkey":"0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","withdrawalCredentials":"0x0000000000000000000000000000000000000000000000000000000000000000","amount":"0x0","signature":"0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","index":"0x0"}"#,
+            ),
+            (
+                TaggedRequest::from(withdrawal),
+                r#"{"type":"withdrawal","sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","validatorPubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","amount":"0x162"}"#,
+            ),
+            (
+                TaggedRequest::from(consolidation),
+                r#"{"type":"consolidation","sourceAddress":"0xae0e8770147aaa6828a0d6f642504663f10f7d1e","sourcePubkey":"0x8e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b","targetPubkey":"0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"}"#,
+            ),
+        ];
+        for (request, json) in cases {
+            assert_eq!(serde_json::to_string(&request).unwrap(), json);
+            assert_eq!(serde_json::from_str::<TaggedRequest>(json).unwrap(), request);
+            assert_eq!(TaggedRequest::from(Request::from(request)), request);
+        }
+
+        assert_eq!(WithdrawalRequest::try_from(TaggedRequest::from(withdrawal)), Ok(withdrawal));
+        assert_eq!(
+            WithdrawalRequest::try_from(TaggedRequest::from(consolidation)),
+            Err(TaggedRequest::Consolidation(consolidation))
+        );
+    }
+
+    #[test]
+    fn prefixed_request_bytes() {
+        let requests = [
+            Request::from(DepositRequest::default()),
+            Request::from(sample_request()),
+            Request::from(ConsolidationRequest::default()),
+        ];
+        let types = [DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE, CONSOLIDATION_REQUEST_TYPE];
+        for (request, ty) in requests.into_iter().zip(types) {
+            let bytes = PrefixedRequestBytes::from(request);
+            assert_eq!(bytes.request_type(), Some(ty));
+            assert_eq!(bytes.as_bytes()[..], request.encoded_7685());
+            assert_eq!(bytes.try_decode().unwrap(), request);
+        }
+
+        let mut set: Vec<_> =
+            requests.iter().rev().copied().map(PrefixedRequestBytes::from).collect();
+        set.sort();
+        assert_eq!(set.iter().map(|r| r.request_type().unwrap()).collect::<Vec<_>>(), types);
+
+        assert_eq!(PrefixedRequestBytes::default().request_type(), None);
+        assert!(matches!(
+            PrefixedRequestBytes::default().try_decode(),
+            Err(Eip7685Error::MissingType)
+        ));
+        let mut trailing = requests[1].encoded_7685();
+        trailing.push(0);
+        assert!(PrefixedRequestBytes::from(Bytes::from(trailing)).try_decode().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn flat_requests_hash() {
+        assert_eq!(requests_hash(&[]), EMPTY_REQUESTS_HASH);
+        let empty: Vec<TypedRequestData> =
+            [DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE, CONSOLIDATION_REQUEST_TYPE]
+                .iter()
+                .map(|ty| Bytes::copy_from_slice(&[*ty]).into())
+                .collect();
+        assert_eq!(requests_hash(&empty), EMPTY_REQUESTS_HASH);
+
+        // computed with an independent implementation of `compute_requests_hash` from the EIP
+        let withdrawal = sample_request();
+        let withdrawals =
+            TypedRequestData::from_packed(WITHDRAWAL_REQUEST_TYPE, &[withdrawal]).unwrap();
+        assert_eq!(withdrawals.request_type(), Some(WITHDRAWAL_REQUEST_TYPE));
+        assert_eq!(withdrawals.request_data(), withdrawal.to_compact());
+        assert_eq!(
+            requests_hash(&[empty[0].clone(), withdrawals, empty[2].clone()]),
+            b256!("46a9e79ea7991a14cddc03e8de35bb83e544e0ad49132c0c48338e09c3cb7639")
+        );
+
+        // the vector of `Requests::requests_hash` in alloy-eips 1.x, also used by reth
+        assert_eq!(
+            requests_hash(&[
+                TypedRequestData(Bytes::from_static(&[0x00, 0x0a, 0x0b, 0x0c])),
+                TypedRequestData(Bytes::from_static(&[0x01, 0x0d, 0x0e, 0x0f])),
+            ]),
+            b256!("be3a57667b9bb9e0275019c0faf0f415fdc8385a408fd03e13a5c50615e3530c")
+        );
+        assert_eq!(TypedRequestData::from_packed::<WithdrawalRequest>(1, &[]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn assemble_mixed_block_requests() {
+        let withdrawal = sample_request();
+        let withdrawals = [withdrawal, WithdrawalRequest::default()];
+        let consolidations = [ConsolidationRequest::default()];
+
+        let (requests, hash) = assemble_block_requests(&[], &withdrawals, &consolidations);
+        assert_eq!(
+            requests.iter().map(Encodable7685::request_type).collect::<Vec<_>>(),
+            [WITHDRAWAL_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE, CONSOLIDATION_REQUEST_TYPE]
+        );
+        assert_eq!(requests.0[0], Request::WithdrawalRequest(withdrawal));
+        // computed with an independent implementation of `compute_requests_hash` from the EIP
+        assert_eq!(hash, b256!("31a527147081fede219456387db5780de2a23d169e237b0d506c36c27b1bac77"));
+
+        let (requests, hash) = assemble_block_requests(&[], &[], &[]);
+        assert!(requests.is_empty());
+        assert_eq!(hash, EMPTY_REQUESTS_HASH);
+    }
+
+    #[test]
+    fn requests_builder() {
+        assert!(Requests::with_capacity(8).0.capacity() >= 8);
+
+        let withdrawal = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let mut builder = RequestsBuilder::with_capacity(1, 2, 1);
+        builder.push(ConsolidationRequest::default());
+        builder.push(withdrawal);
+        builder.push(DepositRequest::default());
+        builder.push(Request::WithdrawalRequest(WithdrawalRequest::default()));
+        assert_eq!(builder.len(), 4);
+        assert_eq!(builder.count(RequestType::Withdrawal), 2);
+        assert_eq!(builder.count(RequestType::Unknown(0x03)), 0);
+
+        #[cfg(feature = "sha2")]
+        assert_eq!(
+            builder.clone().build_with_hash(),
+            assemble_block_requests(
+                &[DepositRequest::default()],
+                &[withdrawal, WithdrawalRequest::default()],
+                &[ConsolidationRequest::default()]
+            )
+        );
+
+        let requests = builder.build();
+        assert_eq!(requests.0.capacity(), requests.len());
+        assert_eq!(
+            requests.0,
+            [
+                Request::DepositRequest(DepositRequest::default()),
+                Request::WithdrawalRequest(withdrawal),
+                Request::WithdrawalRequest(WithdrawalRequest::default()),
+                Request::ConsolidationRequest(ConsolidationRequest::default()),
+            ]
+        );
+        assert!(RequestsBuilder::default().build().is_empty());
+    }
+
+    #[test]
+    fn into_request_bytes() {
+        let withdrawal = sample_request();
+        let bytes = withdrawal.into_request_bytes();
+        assert_eq!(bytes, bytes!("01f84994ae0e8770147aaa6828a0d6f642504663f10f7d1eb08e8d8749f6bc79b78be7cc6e49ff640e608454840c360b344c3a4d9b7428e280e7f40d2271bad65d8cbbfdd43cb8793b820162"));
+        assert_eq!(Request::from(withdrawal).into_request_bytes(), bytes);
+
+        assert_eq!(DepositRequest::default().into_request_bytes()[0], DEPOSIT_REQUEST_TYPE);
+        assert_eq!(
+            ConsolidationRequest::default().into_request_bytes()[0],
+            CONSOLIDATION_REQUEST_TYPE
+        );
+    }
+
+    #[test]
+    fn validate_request_ordering() {
+        let request = |bytes: &[u8]| TypedRequestData(Bytes::copy_from_slice(bytes));
+
+        assert_eq!(validate_ordering(&[]), Ok(()));
+        assert_eq!(
+            validate_ordering(&[request(&[0, 1]), request(&[1, 1, 2]), request(&[2, 1])]),
+            Ok(())
+        );
+        assert_eq!(validate_ordering(&[request(&[0, 1]), request(&[2, 1])]), Ok(()));
+
+        // all requests of a type belong to a single element
+        let err =
+            validate_ordering(&[request(&[0, 1]), request(&[1, 1]), request(&[1, 2])]).unwrap_err();
+        assert_eq!(err, OrderingError::DuplicateType { index: 2 });
+        assert_eq!(err.index(), 2);
+
+        let err =
+            validate_ordering(&[request(&[0, 1]), request(&[2, 1]), request(&[1, 1])]).unwrap_err();
+        assert_eq!(err, OrderingError::OutOfOrder { index: 2 });
+        assert_eq!(err.index(), 2);
+
+        assert_eq!(
+            validate_ordering(&[request(&[0, 1]), request(&[1])]),
+            Err(OrderingError::EmptyRequest { index: 1 })
+        );
+        assert_eq!(
+            validate_ordering(&[request(&[])]),
+            Err(OrderingError::EmptyRequest { index: 0 })
+        );
+    }
+
+    #[test]
+    fn peek_request_data_header() {
+        let request = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let encoded = alloy_rlp::encode(request);
+        let header = peek_rlp_header(&encoded).unwrap();
+        assert!(header.list);
+        assert_eq!(header.payload_length, 73);
+        assert_eq!(header.length() + header.payload_length, encoded.len());
+
+        // trailing bytes are ignored, a truncated payload is not
+        assert_eq!(peek_rlp_header(&[&encoded[..], &[0x80]].concat()), Ok(header));
+        assert_eq!(peek_rlp_header(&encoded[..2]), Err(alloy_rlp::Error::InputTooShort));
+        assert_eq!(peek_rlp_header(&[]), Err(alloy_rlp::Error::InputTooShort));
+
+        let header = peek_rlp_header(&[0x82, 0x01, 0x62]).unwrap();
+        assert!(!header.list);
+        assert_eq!(header.payload_length, 2);
+    }
+
+    #[test]
+    fn decode_all_reports_offset() {
+        let requests = [
+            Request::WithdrawalRequest(WithdrawalRequest {
+                amount: Gwei(354),
+                ..Default::default()
+            }),
+            Request::ConsolidationRequest(ConsolidationRequest::default()),
+            Request::DepositRequest(DepositRequest::default()),
+        ];
+        let encoded: Vec<u8> = requests.iter().flat_map(Encodable7685::encoded_7685).collect();
+        assert_eq!(decode_all_with_offsets(&encoded), Ok(requests.to_vec()));
+        assert_eq!(decode_all_with_offsets(&[]), Ok(vec![]));
+
+        let third = requests[0].encoded_7685().len() + requests[1].encoded_7685().len();
+        assert_eq!(
+            decode_all_with_offsets(&encoded[..third + 10]),
+            Err((third, alloy_rlp::Error::InputTooShort))
+        );
+
+        let mut unknown_type = encoded;
+        unknown_type[third] = 0x03;
+        assert_eq!(decode_all_with_offsets(&unknown_type).unwrap_err().0, third);
+    }
+
+    #[test]
+    fn raw_request_type() {
+        assert_eq!(request_type(&[]), None);
+
+        let request = Request::WithdrawalRequest(WithdrawalRequest::default());
+        let encoded = request.encoded_7685();
+        assert_eq!(request_type(&encoded), Some(WITHDRAWAL_REQUEST_TYPE));
+        assert_eq!(RequestType::from(encoded[0]), RequestType::Withdrawal);
+
+        assert_eq!(RequestType::from(0x00), RequestType::Deposit);
+        assert_eq!(RequestType::from(0x01), RequestType::Withdrawal);
+        assert_eq!(RequestType::from(0x02), RequestType::Consolidation);
+        assert_eq!(RequestType::from(0x03), RequestType::Unknown(0x03));
+        assert_eq!(
+            request_type(&[0xff, 0xc0]).map(RequestType::from),
+            Some(RequestType::Unknown(0xff))
+        );
+
+        for ty in 0..=u8::MAX {
+            assert_eq!(u8::from(RequestType::from(ty)), ty);
+        }
+    }
+
+    #[test]
+    fn request_types_table() {
+        let types = [
+            DepositRequest::default().request_type(),
+            WithdrawalRequest::default().request_type(),
+            ConsolidationRequest::default().request_type(),
+        ];
+        for ty in types {
+            assert!(REQUEST_TYPES.iter().any(|(known, _)| *known == ty), "{ty}");
+        }
+        assert_eq!(REQUEST_TYPES.len(), types.len());
+        assert!(REQUEST_TYPES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        assert_eq!(type_name(WITHDRAWAL_REQUEST_TYPE), Some("withdrawal"));
+        assert_eq!(type_name(0x03), None);
+    }
+
+    #[test]
+    fn encode_decode_mixed_requests() {
+        let requests = vec![
+            Request::WithdrawalRequest(sample_request()),
+            Request::DepositRequest(DepositRequest {
+                pubkey: FixedBytes::repeat_byte(0x11),
+                withdrawal_credentials: B256::repeat_byte(0x22),
+                amount: 32_000_000_000,
+                signature: FixedBytes::repeat_byte(0x33),
+                index: 7,
+            }),
+            Request::ConsolidationRequest(ConsolidationRequest {
+                source_address: Address::repeat_byte(0x44),
+                source_pubkey: FixedBytes::repeat_byte(0x55),
+                target_pubkey: FixedBytes::repeat_byte(0x66),
+            }),
+            Request::WithdrawalRequest(WithdrawalRequest::default()),
+        ];
+
+        let mut buf = Vec::new();
+        for request in &requests {
+            request.encode_7685(&mut buf);
+        }
+        assert_eq!(buf[0], 1);
+
+        let mut slice = buf.as_slice();
+        let mut decoded = Vec::new();
+        while !slice.is_empty() {
+            decoded.push(Request::decode_7685(&mut slice).unwrap());
+        }
+        assert_eq!(decoded, requests);
+    }
+
+    #[test]
+    #[cfg(feature = "trie")]
+    fn requests_root() {
+        assert_eq!(Requests::default().requests_root(), alloy_trie::EMPTY_ROOT_HASH);
+
+        let withdrawal = Request::WithdrawalRequest(sample_request());
+        let mut requests = Requests::default();
+        requests.push(withdrawal);
+        // the roots are cross-checked against `alloy_trie::root::ordered_trie_root_with_encoder`
+        // of alloy-trie 0.7 over the same `iter_encoded` items
+        assert_eq!(
+            requests.requests_root(),
+            b256!("d84724275179e04005e041fe5a7e7580a3283b5ab80274df03a32685da5fc462")
+        );
+
+        // requests are ordered by type before computing the root
+        requests.push(Request::ConsolidationRequest(ConsolidationRequest {
+            source_address: Address::repeat_byte(0x44),
+            source_pubkey: FixedBytes::repeat_byte(0x55),
+            target_pubkey: FixedBytes::repeat_byte(0x66),
+        }));
+        requests.push(Request::WithdrawalRequest(WithdrawalRequest::default()));
+        requests.push(Request::DepositRequest(DepositRequest {
+            pubkey: FixedBytes::repeat_byte(0x11),
+            withdrawal_credentials: B256::repeat_byte(0x22),
+            amount: 32_000_000_000,
+            signature: FixedBytes::repeat_byte(0x33),
+            index: 7,
+        }));
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            requests.requests_root(),
+            b256!("ded0b30e2e80cae818e2c43968d868e6ccbf6c1c3600580e360a7b95f1f519eb")
+        );
+
+        // more than 0x7f items, so that the index adjustment of the trie keys is exercised
+        let requests = Requests(
+            (0..130)
+                .map(|amount| {
+                    WithdrawalRequest { amount: Gwei(amount), ..Default::default() }.into()
+                })
+                .collect(),
+        );
+        assert_eq!(
+            requests.requests_root(),
+            b256!("91dc2385bdd2401c16c1dfb44d289cf39fb4c0d7090b0a752fc0435dc8b506cd")
+        );
+    }
+
+    #[test]
+    fn iter_encoded() {
+        assert_eq!(Requests::default().iter_encoded().count(), 0);
+
+        let deposit = DepositRequest {
+            pubkey: FixedBytes::repeat_byte(0x11),
+            withdrawal_credentials: B256::repeat_byte(0x22),
+            amount: 32_000_000_000,
+            signature: FixedBytes::repeat_byte(0x33),
+            index: 7,
+        };
+        let first = WithdrawalRequest { amount: Gwei(354), ..Default::default() };
+        let second = WithdrawalRequest::default();
+        let consolidation = ConsolidationRequest::default();
+        let requests =
+            Requests(vec![consolidation.into(), first.into(), deposit.into(), second.into()]);
+
+        let prefixed = |ty: u8, payload: Vec<u8>| Bytes::from([vec![ty], payload].concat());
+        assert_eq!(
+            requests.iter_encoded().collect::<Vec<_>>(),
+            [
+                prefixed(0x00, alloy_rlp::encode(deposit)),
+                prefixed(0x01, alloy_rlp::encode(first)),
+                prefixed(0x01, alloy_rlp::encode(second)),
+                prefixed(0x02, alloy_rlp::encode(consolidation)),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn requests_hash_of_list() {
+        assert_eq!(Requests::default().requests_hash(), EMPTY_REQUESTS_HASH);
+
+        let withdrawal = sample_request();
+        let consolidation = ConsolidationRequest::default();
+        let requests = Requests(vec![
+            consolidation.into(),
+            withdrawal.into(),
+            WithdrawalRequest::default().into(),
+        ]);
+        let (_, expected) = assemble_block_requests(
+            &[],
+            &[withdrawal, WithdrawalRequest::default()],
+            &[consolidation],
+        );
+        assert_eq!(requests.requests_hash(), expected);
+        assert_eq!(
+            requests.requests_hash(),
+            b256!("31a527147081fede219456387db5780de2a23d169e237b0d506c36c27b1bac77")
+        );
+
+        // the per-request encodings are a different commitment input
+        let encoded: Vec<TypedRequestData> =
+            requests.iter_encoded().map(TypedRequestData::from).collect();
+        assert_ne!(requests_hash(&encoded), expected);
+    }
+
+    #[test]
+    fn encoded_len() {
+        assert_eq!(Requests::default().encoded_len(), 0);
+
+        let requests = Requests(vec![
+            ConsolidationRequest::default().into(),
+            WithdrawalRequest { amount: Gwei(354), ..Default::default() }.into(),
+            WithdrawalRequest { amount: Gwei::MAX, ..Default::default() }.into(),
+            DepositRequest { amount: 32_000_000_000, ..Default::default() }.into(),
+        ]);
+        let encoded: Vec<u8> = requests.iter_encoded().flatten().collect();
+        assert_eq!(requests.encoded_len(), encoded.len());
+    }
+
+    #[test]
+    fn decode_invalid_request_type() {
+        assert!(matches!(
+            Request::decode_7685(&mut [0x03, 0xc0].as_slice()),
+            Err(Eip7685Error::UnexpectedType(0x03))
+        ));
+        assert!(matches!(Request::decode_7685(&mut [].as_slice()), Err(Eip7685Error::MissingType)));
+    }
+}
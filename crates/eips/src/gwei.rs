@@ -0,0 +1,182 @@
+//! A strongly typed amount of ether in gwei, shared by the request types of the consensus layer.
+
+use crate::eip4895::GWEI_TO_WEI;
+use alloy_primitives::U256;
+use alloy_rlp::{RlpDecodableWrapper, RlpEncodableWrapper};
+use core::fmt;
+
+/// An amount of ether in gwei, i.e. in units of `1e-9` ether.
+///
+/// This is encoded as a plain [`u64`] in RLP and SSZ, and as a `0x`-prefixed hex quantity in serde.
+/// Decimal strings and JSON numbers are also accepted when deserializing.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    RlpEncodableWrapper,
+    RlpDecodableWrapper,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "ssz", derive(ssz_derive::Encode, ssz_derive::Decode))]
+#[cfg_attr(feature = "ssz", ssz(struct_behaviour = "transparent"))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct Gwei(#[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))] pub u64);
+
+impl Gwei {
+    /// Zero gwei.
+    pub const ZERO: Self = Self(0);
+
+    /// The largest representable amount.
+    pub const MAX: Self = Self(u64::MAX);
+
+    /// Creates a new amount from a value in gwei.
+    pub const fn new(gwei: u64) -> Self {
+        Self(gwei)
+    }
+
+    /// Returns the amount in gwei.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Converts the amount to wei.
+    pub fn to_wei(self) -> U256 {
+        U256::from(self.0) * U256::from(GWEI_TO_WEI)
+    }
+
+    /// Converts an amount in wei to gwei.
+    ///
+    /// Returns `None` if the amount is not a whole number of gwei, or does not fit in a [`u64`]
+    /// once converted to gwei.
+    pub fn from_wei_checked(wei: U256) -> Option<Self> {
+        let (gwei, remainder) = wei.div_rem(U256::from(GWEI_TO_WEI));
+        if !remainder.is_zero() {
+            return None;
+        }
+        u64::try_from(gwei).ok().map(Self)
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(gwei) => Some(Self(gwei)),
+            None => None,
+        }
+    }
+
+    /// Subtracts two amounts, returning `None` on underflow.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(gwei) => Some(Self(gwei)),
+            None => None,
+        }
+    }
+
+    /// Adds two amounts, saturating at [`Gwei::MAX`].
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts two amounts, saturating at [`Gwei::ZERO`].
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl From<u64> for Gwei {
+    fn from(gwei: u64) -> Self {
+        Self(gwei)
+    }
+}
+
+impl From<Gwei> for u64 {
+    fn from(gwei: Gwei) -> Self {
+        gwei.0
+    }
+}
+
+impl fmt::Display for Gwei {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} gwei", self.0)
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl tree_hash::TreeHash for Gwei {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        u64::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.0.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        u64::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> tree_hash::Hash256 {
+        self.0.tree_hash_root()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wei_conversions() {
+        let gwei = Gwei(354);
+        assert_eq!(gwei.to_wei(), U256::from(354_000_000_000u64));
+        assert_eq!(Gwei::from_wei_checked(gwei.to_wei()), Some(gwei));
+        assert_eq!(Gwei::from_wei_checked(Gwei::MAX.to_wei()), Some(Gwei::MAX));
+
+        assert_eq!(Gwei::from_wei_checked(U256::from(GWEI_TO_WEI + 1)), None);
+        assert_eq!(Gwei::from_wei_checked(Gwei::MAX.to_wei() + U256::from(GWEI_TO_WEI)), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Gwei(1).checked_add(Gwei(2)), Some(Gwei(3)));
+        assert_eq!(Gwei::MAX.checked_add(Gwei(1)), None);
+        assert_eq!(Gwei(1).checked_sub(Gwei(2)), None);
+        assert_eq!(Gwei::MAX.saturating_add(Gwei(1)), Gwei::MAX);
+        assert_eq!(Gwei(1).saturating_sub(Gwei(2)), Gwei::ZERO);
+        assert!(Gwei::ZERO.is_zero());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Gwei(32_000_000_000).to_string(), "32000000000 gwei");
+    }
+
+    #[test]
+    fn rlp_matches_u64() {
+        for gwei in [0, 1, 354, u64::MAX] {
+            assert_eq!(alloy_rlp::encode(Gwei(gwei)), alloy_rlp::encode(gwei));
+            assert_eq!(alloy_rlp::decode_exact(alloy_rlp::encode(gwei)), Ok(Gwei(gwei)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_hex_quantity() {
+        assert_eq!(serde_json::to_string(&Gwei(354)).unwrap(), r#""0x162""#);
+        assert_eq!(serde_json::from_str::<Gwei>(r#""0x162""#).unwrap(), Gwei(354));
+        assert_eq!(serde_json::from_str::<Gwei>("354").unwrap(), Gwei(354));
+        assert_eq!(serde_json::from_str::<Gwei>(r#""354""#).unwrap(), Gwei(354));
+        assert!(serde_json::from_str::<Gwei>(r#""18446744073709551616""#).is_err());
+    }
+}
@@ -11,6 +11,10 @@
 #[macro_use]
 extern crate alloc;
 
+// only depended on to pin a version that `alloy-dyn-abi` compiles against
+#[cfg(feature = "dyn-abi")]
+use alloy_sol_type_parser as _;
+
 pub mod eip1559;
 pub use eip1559::calc_next_block_base_fee;
 
@@ -43,3 +47,8 @@ pub mod eip7251;
 pub mod eip7685;
 
 pub mod eip7702;
+
+pub mod gwei;
+pub use gwei::Gwei;
+
+pub mod system_contract;
@@ -0,0 +1,17 @@
+//! Common interface of system contracts, such as the request predeploys introduced in the
+//! [Prague hardfork](https://github.com/ethereum/execution-apis/blob/main/src/engine/prague.md).
+
+use alloy_primitives::{Address, B256};
+
+/// A system contract deployed at a fixed address.
+///
+/// This is implemented for zero-sized marker types, so that the details of all system contracts
+/// can be accessed uniformly, including in const contexts.
+pub trait SystemContract {
+    /// The address of the contract.
+    const ADDRESS: Address;
+    /// The code of the contract.
+    const CODE: &'static [u8];
+    /// The code hash of the contract, i.e. `keccak256(CODE)`.
+    const CODE_HASH: B256;
+}
@@ -0,0 +1,68 @@
+use alloy_eips::{
+    eip7002::{SourceAddress, WithdrawalRequest},
+    Gwei,
+};
+use alloy_primitives::{hex, Address, FixedBytes};
+use serde_json::Value;
+
+const FIXTURE: &str = include_str!("../../test_data/eip7002/withdrawal_requests.json");
+
+/// A withdrawal request fixture, with its expected RLP and packed encodings.
+struct Case {
+    json: Value,
+    request: WithdrawalRequest,
+    rlp: Vec<u8>,
+    packed: Vec<u8>,
+}
+
+fn cases() -> Vec<Case> {
+    let fixture: Vec<Value> = serde_json::from_str(FIXTURE).unwrap();
+    let hex_field = |value: &Value| hex::decode(value.as_str().unwrap()).unwrap();
+    fixture
+        .into_iter()
+        .map(|case| {
+            let json = case["request"].clone();
+            let amount = json["amount"].as_str().unwrap().trim_start_matches("0x");
+            let request = WithdrawalRequest {
+                source_address: SourceAddress(Address::from_slice(&hex_field(
+                    &json["sourceAddress"],
+                ))),
+                validator_pubkey: FixedBytes::from_slice(&hex_field(&json["validatorPubkey"])),
+                amount: Gwei(u64::from_str_radix(amount, 16).unwrap()),
+            };
+            Case { rlp: hex_field(&case["rlp"]), packed: hex_field(&case["packed"]), json, request }
+        })
+        .collect()
+}
+
+#[test]
+fn golden_fixture_coverage() {
+    let cases = cases();
+    assert!(cases.iter().any(|case| case.request.amount.is_zero()));
+    assert!(cases.iter().any(|case| case.request.amount.get() > u64::MAX - 16));
+}
+
+#[test]
+fn golden_rlp_encoding() {
+    for case in cases() {
+        assert_eq!(alloy_rlp::encode(case.request), case.rlp, "{}", case.json);
+        assert_eq!(alloy_rlp::decode_exact::<WithdrawalRequest>(&case.rlp), Ok(case.request));
+    }
+}
+
+#[test]
+fn golden_packed_encoding() {
+    for case in cases() {
+        assert_eq!(case.request.to_compact()[..], case.packed, "{}", case.json);
+        assert_eq!(WithdrawalRequest::try_from(&case.packed[..]), Ok(case.request));
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn golden_serde_encoding() {
+    for case in cases() {
+        assert_eq!(serde_json::to_value(case.request).unwrap(), case.json);
+        assert_eq!(serde_json::from_value::<WithdrawalRequest>(case.json).unwrap(), case.request);
+    }
+}
@@ -0,0 +1,3 @@
+//! Integration tests for `alloy-eips`.
+
+mod eip7002;